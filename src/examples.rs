@@ -56,5 +56,5 @@ fn main() {
     println!("==============");
     directed_graph();
 
-    println!("");
+    println!();
 }
@@ -0,0 +1,205 @@
+//! Approximation algorithms.
+use crate::graph::{Graph, Undirected};
+use crate::weighted::{Weight, WeightedGraph};
+use crate::weighted_search::{single_source_dijkstra, MinScored};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Prim's minimum spanning tree of the component containing `root`.
+fn prim_tree<T, W>(g: &WeightedGraph<T, Undirected, W>, root: &T) -> WeightedGraph<T, Undirected, W>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    let mut tree: WeightedGraph<T, Undirected, W> = WeightedGraph::new();
+    let mut heap: BinaryHeap<MinScored<W, (T, T)>> = BinaryHeap::new();
+    tree.add_node(root.clone());
+    for v in g.adj(root).into_iter().flatten() {
+        heap.push(MinScored(
+            g.weight(root, v).unwrap(),
+            (root.clone(), v.clone()),
+        ));
+    }
+
+    while let Some(MinScored(weight, (u, v))) = heap.pop() {
+        if tree.adj(&v).is_some() {
+            continue;
+        }
+        tree.add_edge(u, v.clone(), weight);
+        for w in g.adj(&v).expect("No such node in a graph") {
+            if tree.adj(w).is_none() {
+                heap.push(MinScored(g.weight(&v, w).unwrap(), (v.clone(), w.clone())));
+            }
+        }
+    }
+    tree
+}
+
+/// Approximates a minimum Steiner tree connecting all `terminals`.
+///
+/// Uses the metric closure 2-approximation: a minimum spanning tree is built
+/// over shortest-path distances between terminals, its edges are expanded
+/// back into paths of the original graph, and non-terminal leaves are pruned.
+/// Terminals are assumed to lie in a single connected component.
+pub fn steiner_tree<T, W>(
+    g: &WeightedGraph<T, Undirected, W>,
+    terminals: &HashSet<T>,
+) -> Vec<(T, T)>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    let searches: HashMap<&T, _> = terminals
+        .iter()
+        .map(|t| (t, single_source_dijkstra(g, t.clone())))
+        .collect();
+
+    let mut closure: WeightedGraph<T, Undirected, W> = WeightedGraph::new();
+    for (u, (dist, _)) in searches.iter() {
+        for v in terminals.iter().filter(|v| *v != *u) {
+            if let Some(d) = dist.get(v) {
+                closure.add_edge((*u).clone(), v.clone(), *d);
+            }
+        }
+    }
+
+    let root = match terminals.iter().next() {
+        Some(root) => root,
+        None => return vec![],
+    };
+    let closure_tree = prim_tree(&closure, root);
+
+    let mut expanded: WeightedGraph<T, Undirected, W> = WeightedGraph::new();
+    for (u, v) in closure_tree.graph().edges::<Vec<_>>() {
+        let previous = &searches[&u].1;
+        let mut current = v;
+        while current != u {
+            let parent = previous[&current].clone();
+            let weight = g.weight(&parent, &current).unwrap();
+            expanded.add_edge(parent.clone(), current, weight);
+            current = parent;
+        }
+    }
+
+    let tree = prim_tree(&expanded, root);
+    let mut adj: HashMap<T, HashSet<T>> = tree
+        .iter()
+        .map(|n| (n.clone(), tree.adj(n).unwrap().clone()))
+        .collect();
+    let mut leaves: Vec<T> = adj
+        .iter()
+        .filter(|(n, neighbors)| !terminals.contains(*n) && neighbors.len() == 1)
+        .map(|(n, _)| n.clone())
+        .collect();
+    while let Some(leaf) = leaves.pop() {
+        let parent = adj.remove(&leaf).unwrap().into_iter().next().unwrap();
+        let neighbors = adj.get_mut(&parent).unwrap();
+        neighbors.remove(&leaf);
+        if !terminals.contains(&parent) && neighbors.len() == 1 {
+            leaves.push(parent);
+        }
+    }
+
+    let mut edges: Vec<(T, T)> = Vec::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+    for (u, neighbors) in adj.iter() {
+        visited.insert(u);
+        for v in neighbors.iter().filter(|v| !visited.contains(v)) {
+            edges.push((u.clone(), v.clone()));
+        }
+    }
+    edges
+}
+
+/// Returns a min-degree elimination order of an undirected graph together with the
+/// treewidth upper bound it gives.
+///
+/// Repeatedly eliminates a node of minimum degree, connecting all its neighbors into a
+/// clique. The bound is the largest degree of a node when it is eliminated, i.e. one less
+/// than the largest clique formed. Self-loops are ignored.
+pub fn min_degree_elimination_order<T>(g: &Graph<T, Undirected>) -> (Vec<T>, usize)
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut adj: HashMap<&T, HashSet<&T>> = g
+        .iter()
+        .map(|u| (u, g.adj(u).unwrap().iter().filter(|v| *v != u).collect()))
+        .collect();
+    let mut order: Vec<T> = Vec::new();
+    let mut width = 0;
+
+    while let Some(u) = adj.iter().min_by_key(|(_, n)| n.len()).map(|(u, _)| *u) {
+        let neighbors = adj.remove(u).unwrap();
+        width = width.max(neighbors.len());
+        for v in neighbors.iter() {
+            let v_adj = adj.get_mut(v).unwrap();
+            v_adj.remove(u);
+            v_adj.extend(neighbors.iter().filter(|w| *w != v));
+        }
+        order.push(u.clone());
+    }
+    (order, width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::connected_components;
+
+    fn weighted_graph() -> WeightedGraph<i8, Undirected, u32> {
+        let mut g: WeightedGraph<i8, Undirected, u32> = WeightedGraph::new();
+        g.add_edge(1, 2, 10);
+        g.add_edge(2, 3, 10);
+        g.add_edge(1, 3, 10);
+        g.add_edge(1, 0, 1);
+        g.add_edge(2, 0, 1);
+        g.add_edge(3, 0, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 5, 1);
+        g
+    }
+
+    #[test]
+    fn steiner_tree_connects_terminals() {
+        let g = weighted_graph();
+        let terminals = HashSet::from([1, 2, 3]);
+        let edges = steiner_tree(&g, &terminals);
+
+        let mut tree: Graph<i8> = Graph::new();
+        tree.add_edges_from(edges.clone());
+        let components = connected_components(&tree);
+        assert_eq!(components.len(), 1);
+        assert!(components[0].is_superset(&terminals));
+        assert_eq!(edges.len(), 3);
+        assert_eq!(tree.nodes::<HashSet<_>>(), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn steiner_tree_single_terminal() {
+        let g = weighted_graph();
+        assert!(steiner_tree(&g, &HashSet::from([4])).is_empty());
+    }
+
+    #[test]
+    fn elimination_order_treewidth() {
+        let mut tree: Graph<i8> = Graph::new();
+        tree.add_edges_from(vec![(1, 2), (1, 3), (3, 4), (3, 5), (5, 6)]);
+        let (order, width) = min_degree_elimination_order(&tree);
+        assert_eq!(order.len(), 6);
+        assert_eq!(order.iter().collect::<HashSet<_>>().len(), 6);
+        assert_eq!(width, 1);
+
+        let mut cycle: Graph<i8> = Graph::new();
+        cycle.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 1)]);
+        assert_eq!(min_degree_elimination_order(&cycle).1, 2);
+
+        let mut k4: Graph<i8> = Graph::new();
+        k4.add_edges_from(vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4), (4, 4)]);
+        assert_eq!(min_degree_elimination_order(&k4).1, 3);
+        assert_eq!(
+            min_degree_elimination_order(&Graph::<i8>::new()),
+            (vec![], 0)
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Degree correlations of a graph.
+use crate::graph::{Graph, Undirected};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Computes the degree assortativity coefficient of an undirected graph, i.e. the
+/// Pearson correlation of degrees at both ends of every edge. The result lies in `[-1, 1]`.
+///
+/// Returns `NaN` if the correlation is undefined, i.e. when the graph has no
+/// edges or all edge ends have the same degree.
+pub fn degree_assortativity<T>(g: &Graph<T, Undirected>) -> f64
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let (mut count, mut sum, mut sum_sq, mut sum_prod) = (0.0, 0.0, 0.0, 0.0);
+    for (u, v) in g.edges::<Vec<_>>() {
+        let (x, y) = (g.degree(&u) as f64, g.degree(&v) as f64);
+        count += 1.0;
+        sum += x;
+        sum_sq += x * x;
+        sum_prod += x * y;
+    }
+
+    let mean = sum / count;
+    let variance = sum_sq / count - mean * mean;
+    if variance.abs() < f64::EPSILON {
+        return f64::NAN;
+    }
+    (sum_prod / count - mean * mean) / variance
+}
+
+/// Computes the rich-club coefficient of an undirected graph, i.e. the density of edges
+/// among nodes of degree greater than `k`. The result lies in `[0, 1]`.
+///
+/// Returns `NaN` if there are fewer than two such nodes. Self-loops are ignored.
+pub fn rich_club_coefficient<T>(g: &Graph<T, Undirected>, k: usize) -> f64
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let rich: HashSet<&T> = g.iter().filter(|u| g.degree(u) > k).collect();
+    let n = rich.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    let ends: usize = rich
+        .iter()
+        .map(|u| {
+            g.adj(u)
+                .unwrap()
+                .iter()
+                .filter(|v| *v != *u && rich.contains(v))
+                .count()
+        })
+        .sum();
+    ends as f64 / (n * (n - 1)) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_is_disassortative() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+        let r = degree_assortativity(&g);
+        assert!(r < 0.0);
+        assert!((r + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn connected_hubs_are_assortative() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+            (5, 6),
+            (6, 7),
+        ]);
+        assert!(degree_assortativity(&g) > 0.0);
+    }
+
+    #[test]
+    fn regular_graph_is_undefined() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1)]);
+        assert!(degree_assortativity(&g).is_nan());
+        assert!(degree_assortativity(&Graph::<i8>::new()).is_nan());
+    }
+
+    #[test]
+    fn dense_rich_club() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]);
+        g.add_edges_from(vec![(1, 5), (2, 6), (3, 7), (4, 8), (5, 6), (4, 4)]);
+        assert_eq!(rich_club_coefficient(&g, 2), 1.0);
+        assert!((rich_club_coefficient(&g, 0) - 11.0 / 28.0).abs() < 1e-12);
+        assert!(rich_club_coefficient(&g, 4).is_nan());
+    }
+}
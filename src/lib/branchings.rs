@@ -0,0 +1,222 @@
+//! Minimum spanning arborescences of directed graphs.
+use crate::graph::Directed;
+use crate::weighted::{Weight, WeightedGraph};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// An edge `(u, v, weight, id)` between node indices, where `id` refers to an edge of the input graph.
+type IndexedEdge<W> = (usize, usize, W, usize);
+
+/// Returns the minimum weight edge entering every node but `root`, or `None` if some node has none.
+fn cheapest_in_edges<W: Weight>(
+    n: usize,
+    root: usize,
+    edges: &[IndexedEdge<W>],
+) -> Option<Vec<usize>> {
+    let mut cheapest: Vec<Option<usize>> = vec![None; n];
+    for (i, (u, v, w, _)) in edges.iter().enumerate() {
+        if u != v && *v != root && cheapest[*v].is_none_or(|j| *w < edges[j].2) {
+            cheapest[*v] = Some(i);
+        }
+    }
+    // A placeholder for the root, which is never read.
+    cheapest[root] = Some(usize::MAX);
+    cheapest.into_iter().collect()
+}
+
+/// Chu–Liu/Edmonds' algorithm on nodes `0..n`. Returns ids of the arborescence edges.
+fn edmonds<W: Weight>(n: usize, root: usize, edges: &[IndexedEdge<W>]) -> Option<Vec<usize>> {
+    let cheapest = cheapest_in_edges(n, root, edges)?;
+    let source = |v: usize| edges[cheapest[v]].0;
+
+    // Contract every cycle formed by the cheapest edges into a single node.
+    let mut component: Vec<Option<usize>> = vec![None; n];
+    let mut on_cycle: Vec<bool> = vec![false; n];
+    let mut walked: Vec<Option<usize>> = vec![None; n];
+    let mut count = 0;
+    for start in 0..n {
+        let mut x = start;
+        while x != root && walked[x].is_none() && component[x].is_none() {
+            walked[x] = Some(start);
+            x = source(x);
+        }
+        if x != root && walked[x] == Some(start) && component[x].is_none() {
+            let mut y = x;
+            loop {
+                component[y] = Some(count);
+                on_cycle[y] = true;
+                y = source(y);
+                if y == x {
+                    break;
+                }
+            }
+            count += 1;
+        }
+    }
+    if count == 0 {
+        return Some(
+            (0..n)
+                .filter(|v| *v != root)
+                .map(|v| edges[cheapest[v]].3)
+                .collect(),
+        );
+    }
+    let component: Vec<usize> = component
+        .into_iter()
+        .map(|c| {
+            c.unwrap_or_else(|| {
+                count += 1;
+                count - 1
+            })
+        })
+        .collect();
+
+    let contracted: Vec<IndexedEdge<W>> = edges
+        .iter()
+        .filter(|(u, v, _, _)| component[*u] != component[*v])
+        .map(|(u, v, w, id)| {
+            let w = if *v == root {
+                *w
+            } else {
+                *w - edges[cheapest[*v]].2
+            };
+            (component[*u], component[*v], w, *id)
+        })
+        .collect();
+    let chosen = edmonds(count, component[root], &contracted)?;
+
+    // Every cycle is entered by exactly one chosen edge; keep the rest of the cycle.
+    let target: HashMap<usize, usize> = edges.iter().map(|(_, v, _, id)| (*id, *v)).collect();
+    let entered: HashSet<usize> = chosen.iter().map(|id| target[id]).collect();
+    let mut result = chosen;
+    result.extend(
+        (0..n)
+            .filter(|v| on_cycle[*v] && !entered.contains(v))
+            .map(|v| edges[cheapest[v]].3),
+    );
+    Some(result)
+}
+
+/// Returns edges of a minimum weight spanning arborescence rooted at `root`, i.e. a set of
+/// edges with the least total weight such that every node has a unique path from `root`.
+///
+/// Implements Chu–Liu/Edmonds' algorithm in `O(n * m)` time. Returns `None` if some node
+/// is not reachable from `root`.
+pub fn min_arborescence<T, W>(g: &WeightedGraph<T, Directed, W>, root: T) -> Option<Vec<(T, T, W)>>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    g.adj(&root)?;
+    let nodes: Vec<&T> = g.iter().collect();
+    let index: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, u)| (*u, i)).collect();
+    let mut original: Vec<(T, T, W)> = Vec::new();
+    let mut edges: Vec<IndexedEdge<W>> = Vec::new();
+    for u in nodes.iter() {
+        for v in g.adj(u).unwrap() {
+            let w = g.weight(u, v).unwrap();
+            edges.push((index[u], index[v], w, original.len()));
+            original.push(((*u).clone(), v.clone(), w));
+        }
+    }
+
+    let chosen = edmonds(nodes.len(), index[&root], &edges)?;
+    Some(chosen.into_iter().map(|id| original[id].clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    fn total<W: Weight>(edges: &[(i8, i8, W)]) -> W {
+        edges.iter().fold(W::zero(), |acc, (_, _, w)| acc + *w)
+    }
+
+    /// Checks that every node but `root` has exactly one parent and leads back to `root`.
+    fn is_arborescence(n: usize, root: i8, edges: &[(i8, i8, u32)]) -> bool {
+        let parent: HashMap<i8, i8> = edges.iter().map(|(u, v, _)| (*v, *u)).collect();
+        edges.len() == n - 1
+            && parent.len() == n - 1
+            && !parent.contains_key(&root)
+            && parent.keys().all(|v| {
+                let mut x = *v;
+                for _ in 0..n {
+                    match parent.get(&x) {
+                        Some(u) => x = *u,
+                        None => break,
+                    }
+                }
+                x == root
+            })
+    }
+
+    #[test]
+    fn contracts_cycle() {
+        let mut g: WeightedGraph<i8, Directed, u32> = WeightedGraph::new();
+        g.add_weighted_edges_from([
+            (0, 1, 10),
+            (0, 2, 12),
+            (1, 2, 1),
+            (2, 1, 1),
+            (2, 3, 5),
+            (3, 1, 2),
+        ]);
+        let actual = min_arborescence(&g, 0).unwrap();
+        assert!(is_arborescence(4, 0, &actual));
+        assert_eq!(total(&actual), 16);
+        assert!(actual.contains(&(0, 1, 10)));
+
+        g.add_edge(4, 1, 1);
+        assert_eq!(min_arborescence(&g, 0), None);
+        assert_eq!(min_arborescence(&g, 9), None);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(7);
+        for _ in 0..30 {
+            let n = 5;
+            let mut g: WeightedGraph<i8, Directed, u32> = WeightedGraph::new();
+            (0..n).for_each(|u| g.add_node(u));
+            for u in 0..n {
+                for v in 0..n {
+                    if u != v && rng.next_f64() < 0.6 {
+                        g.add_edge(u, v, rng.gen_index(10) as u32);
+                    }
+                }
+            }
+
+            // Try every choice of a parent for each non-root node.
+            let mut best: Option<u32> = None;
+            let mut choice = vec![0; n as usize];
+            loop {
+                let edges: Vec<(i8, i8, u32)> = (1..n)
+                    .map(|v| {
+                        (
+                            choice[v as usize] as i8,
+                            v,
+                            g.weight(&(choice[v as usize] as i8), &v),
+                        )
+                    })
+                    .filter_map(|(u, v, w)| w.map(|w| (u, v, w)))
+                    .collect();
+                if is_arborescence(n as usize, 0, &edges) {
+                    best = Some(best.map_or(total(&edges), |b| b.min(total(&edges))));
+                }
+                let Some(i) = (1..n as usize).find(|i| choice[*i] + 1 < n as usize) else {
+                    break;
+                };
+                choice[i] += 1;
+                (1..i).for_each(|j| choice[j] = 0);
+            }
+
+            let actual = min_arborescence(&g, 0);
+            if let Some(edges) = &actual {
+                assert!(is_arborescence(n as usize, 0, edges));
+            }
+            assert_eq!(actual.map(|edges| total(&edges)), best);
+        }
+    }
+}
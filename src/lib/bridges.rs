@@ -0,0 +1,53 @@
+//! Bridges in an undirected graph.
+use crate::graph::{Graph, Undirected};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns local bridges, i.e. edges whose ends have no common neighbor, so removing
+/// such an edge makes the distance between its ends greater than 2. Unlike bridges,
+/// local bridges need not disconnect a graph. Every edge is listed once, self-loops are ignored.
+pub fn local_bridges<T>(g: &Graph<T, Undirected>) -> Vec<(T, T)>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut seen: HashSet<&T> = HashSet::new();
+    let mut bridges: Vec<(T, T)> = Vec::new();
+    for u in g.iter() {
+        seen.insert(u);
+        for v in g.adj(u).unwrap() {
+            if !seen.contains(v) && g.neighbors_intersection(u, v).all(|w| w == u || w == v) {
+                bridges.push((u.clone(), v.clone()));
+            }
+        }
+    }
+    bridges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_range_tie() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (1, 4),
+            (1, 1),
+        ]);
+        let actual: Vec<_> = local_bridges(&g)
+            .into_iter()
+            .map(|(u, v)| (u.min(v), u.max(v)))
+            .collect();
+        assert_eq!(actual, vec![(1, 4)]);
+
+        g.add_edges_from(vec![(1, 7), (7, 4)]);
+        assert!(local_bridges(&g).is_empty());
+    }
+}
@@ -0,0 +1,289 @@
+//! Centrality measures.
+use crate::graph::{Directed, Graph, GraphType, Undirected};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns an edge in a canonical form, i.e. with the smaller node first.
+fn canonical_edge<T>(u: &T, v: &T) -> (T, T)
+where
+    T: Clone + Ord,
+{
+    if u <= v {
+        (u.clone(), v.clone())
+    } else {
+        (v.clone(), u.clone())
+    }
+}
+
+/// Normalizes a per-node score by `n - 1`. Returns an empty map for graphs with fewer than two nodes.
+fn normalized_centrality<T, G, F>(g: &Graph<T, G>, score: F) -> HashMap<T, f64>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    F: Fn(&T) -> usize,
+{
+    let n = g.node_count();
+    if n < 2 {
+        return HashMap::new();
+    }
+    g.iter()
+        .map(|u| (u.clone(), score(u) as f64 / (n - 1) as f64))
+        .collect()
+}
+
+/// Computes the degree centrality of every node, i.e. its degree divided by `n - 1`.
+/// Returns an empty map for graphs with fewer than two nodes.
+pub fn degree_centrality<T>(g: &Graph<T, Undirected>) -> HashMap<T, f64>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    normalized_centrality(g, |u| g.degree(u))
+}
+
+/// Computes the in-degree centrality of every node, i.e. its in-degree divided by `n - 1`.
+/// Returns an empty map for graphs with fewer than two nodes.
+pub fn in_degree_centrality<T>(g: &Graph<T, Directed>) -> HashMap<T, f64>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    normalized_centrality(g, |u| g.in_degree(u))
+}
+
+/// Computes the out-degree centrality of every node, i.e. its out-degree divided by `n - 1`.
+/// Returns an empty map for graphs with fewer than two nodes.
+pub fn out_degree_centrality<T>(g: &Graph<T, Directed>) -> HashMap<T, f64>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    normalized_centrality(g, |u| g.degree_or_zero(u))
+}
+
+/// Computes the betweenness centrality of every edge in an undirected graph
+/// using Brandes' algorithm.
+///
+/// Each edge is keyed once as `(u, v)` with `u <= v`, hence `T: Ord`. Scores are normalized
+/// by `n * (n - 1)`, where `n` is the number of nodes.
+pub fn edge_betweenness_centrality<T>(g: &Graph<T, Undirected>) -> HashMap<(T, T), f64>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+{
+    let mut betweenness: HashMap<(T, T), f64> = g
+        .edges::<Vec<_>>()
+        .iter()
+        .map(|(u, v)| (canonical_edge(u, v), 0.0))
+        .collect();
+
+    for source in g.iter() {
+        let mut stack: Vec<&T> = Vec::new();
+        let mut predecessors: HashMap<&T, Vec<&T>> = HashMap::new();
+        let mut sigma: HashMap<&T, f64> = HashMap::from([(source, 1.0)]);
+        let mut dist: HashMap<&T, usize> = HashMap::from([(source, 0)]);
+        let mut queue: VecDeque<&T> = VecDeque::from([source]);
+
+        while let Some(node) = queue.pop_front() {
+            stack.push(node);
+            for neighbor in g.adj(node).expect("No such node in a graph") {
+                if !dist.contains_key(neighbor) {
+                    dist.insert(neighbor, dist[node] + 1);
+                    queue.push_back(neighbor);
+                }
+                if dist[neighbor] == dist[node] + 1 {
+                    *sigma.entry(neighbor).or_default() += sigma[node];
+                    predecessors.entry(neighbor).or_default().push(node);
+                }
+            }
+        }
+
+        let mut delta: HashMap<&T, f64> = HashMap::new();
+        while let Some(node) = stack.pop() {
+            let coeff = (1.0 + delta.get(node).copied().unwrap_or(0.0)) / sigma[node];
+            for pred in predecessors.get(node).into_iter().flatten() {
+                let c = sigma[pred] * coeff;
+                *betweenness.get_mut(&canonical_edge(*pred, node)).unwrap() += c;
+                *delta.entry(pred).or_default() += c;
+            }
+        }
+    }
+
+    let n = g.iter().count();
+    if n > 1 {
+        let scale = 1.0 / (n * (n - 1)) as f64;
+        betweenness.values_mut().for_each(|b| *b *= scale);
+    }
+    betweenness
+}
+
+/// Computes the PageRank of every node, treating each undirected edge as a pair of directed edges.
+///
+/// Runs a fixed number of power `iterations`. The rank of nodes without neighbors is spread
+/// evenly over all nodes, so scores sum to one.
+pub fn pagerank_undirected<T>(
+    g: &Graph<T, Undirected>,
+    damping: f64,
+    iterations: usize,
+) -> HashMap<T, f64>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let n = g.node_count() as f64;
+    let mut rank: HashMap<&T, f64> = g.iter().map(|u| (u, 1.0 / n)).collect();
+
+    for _ in 0..iterations {
+        let dangling: f64 = rank
+            .iter()
+            .filter(|(u, _)| g.adj(u).is_none_or(|v| v.is_empty()))
+            .map(|(_, r)| r)
+            .sum();
+        let base = (1.0 - damping) / n + damping * dangling / n;
+        let mut next: HashMap<&T, f64> = g.iter().map(|u| (u, base)).collect();
+
+        for (u, r) in rank.iter() {
+            let neighbors = g.adj(u).unwrap();
+            let share = damping * r / neighbors.len() as f64;
+            for v in neighbors {
+                *next.get_mut(v).unwrap() += share;
+            }
+        }
+        rank = next;
+    }
+    rank.into_iter().map(|(u, r)| (u.clone(), r)).collect()
+}
+
+/// Scales scores to unit L2 norm, unless they are all zero.
+fn l2_normalize<T>(scores: &mut HashMap<T, f64>) {
+    let norm = scores.values().map(|x| x * x).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        scores.values_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// Computes hub and authority scores of every node with a fixed number of HITS `iterations`.
+///
+/// Returns `(hubs, authorities)`, each normalized to unit L2 norm.
+pub fn hits<T>(g: &Graph<T, Directed>, iterations: usize) -> (HashMap<T, f64>, HashMap<T, f64>)
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut hubs: HashMap<T, f64> = g.iter().map(|u| (u.clone(), 1.0)).collect();
+    let mut authorities: HashMap<T, f64> = hubs.clone();
+    l2_normalize(&mut hubs);
+    l2_normalize(&mut authorities);
+
+    for _ in 0..iterations {
+        authorities = g
+            .iter()
+            .map(|v| {
+                (
+                    v.clone(),
+                    g.pred(v).into_iter().flatten().map(|u| hubs[u]).sum(),
+                )
+            })
+            .collect();
+        l2_normalize(&mut authorities);
+        hubs = g
+            .iter()
+            .map(|u| {
+                (
+                    u.clone(),
+                    g.adj(u).into_iter().flatten().map(|v| authorities[v]).sum(),
+                )
+            })
+            .collect();
+        l2_normalize(&mut hubs);
+    }
+    (hubs, authorities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn barbell_graph() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (1, 3), (3, 4), (4, 5), (5, 6), (4, 6)]);
+        g
+    }
+
+    #[test]
+    fn directed_star_degree_centrality() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+        let out = out_degree_centrality(&g);
+        assert_eq!(out[&0], 1.0);
+        assert!((1..=4).all(|u| out[&u] == 0.0));
+        let inc = in_degree_centrality(&g);
+        assert_eq!(inc[&0], 0.0);
+        assert!((1..=4).all(|u| inc[&u] == 0.25));
+    }
+
+    #[test]
+    fn undirected_degree_centrality() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(0, 1), (0, 2), (1, 2), (2, 3)]);
+        let expected = HashMap::from([(0, 2.0 / 3.0), (1, 2.0 / 3.0), (2, 1.0), (3, 1.0 / 3.0)]);
+        assert_eq!(degree_centrality(&g), expected);
+
+        let mut single: Graph<i8> = Graph::new();
+        single.add_node(1);
+        assert!(degree_centrality(&single).is_empty());
+    }
+
+    #[test]
+    fn edge_betweenness_bridge_is_highest() {
+        let g = barbell_graph();
+        let actual = edge_betweenness_centrality(&g);
+        let bridge = actual[&(3, 4)];
+        assert_eq!(actual.len(), 7);
+        assert!(actual
+            .iter()
+            .filter(|(e, _)| **e != (3, 4))
+            .all(|(_, b)| *b < bridge));
+    }
+
+    #[test]
+    fn edge_betweenness_path() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        let actual = edge_betweenness_centrality(&g);
+        let expected = HashMap::from([((1, 2), 4.0 / 6.0), ((2, 3), 4.0 / 6.0)]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pagerank_star() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+        g.add_node(5);
+        let actual = pagerank_undirected(&g, 0.85, 100);
+        assert!((actual.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!((1..=5).all(|u| actual[&0] > actual[&u]));
+        assert!((actual[&1] - actual[&4]).abs() < 1e-9);
+        assert!(actual[&1] > actual[&5]);
+    }
+
+    #[test]
+    fn hits_hub_and_authority() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 9),
+            (4, 9),
+            (5, 9),
+            (6, 9),
+            (1, 2),
+        ]);
+        let (hubs, authorities) = hits(&g, 50);
+        assert!(g.iter().filter(|u| **u != 0).all(|u| hubs[&0] > hubs[u]));
+        assert!(g
+            .iter()
+            .filter(|u| **u != 9)
+            .all(|u| authorities[&9] > authorities[u]));
+        assert_eq!(authorities[&0], 0.0);
+        assert_eq!(hubs[&9], 0.0);
+        assert!((hubs.values().map(|x| x * x).sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!((authorities.values().map(|x| x * x).sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+}
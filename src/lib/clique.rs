@@ -0,0 +1,140 @@
+//! Cliques in an undirected graph.
+use crate::graph::{Graph, Undirected};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns all maximal cliques of an undirected graph.
+///
+/// Implements the Bron–Kerbosch algorithm with pivoting. Its worst case
+/// running time is exponential in the number of nodes. Self-loops are ignored.
+pub fn maximal_cliques<T>(g: &Graph<T, Undirected>) -> Vec<HashSet<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut cliques: Vec<HashSet<T>> = Vec::new();
+    bron_kerbosch(
+        g,
+        &mut Vec::new(),
+        g.iter().collect(),
+        HashSet::new(),
+        &mut cliques,
+    );
+    cliques
+}
+
+/// Returns a maximum independent set of an undirected graph, i.e. a largest set of
+/// pairwise non-adjacent nodes.
+///
+/// Finds a maximum clique of the complement with [`maximal_cliques`], so the running
+/// time is exponential in the number of nodes. Self-loops are ignored.
+pub fn maximum_independent_set<T>(g: &Graph<T, Undirected>) -> HashSet<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    maximal_cliques(&g.complement())
+        .into_iter()
+        .max_by_key(|c| c.len())
+        .unwrap_or_default()
+}
+
+/// Neighbors of `u` other than `u` itself.
+fn neighbors<'a, T>(g: &'a Graph<T, Undirected>, u: &'a T) -> impl Iterator<Item = &'a T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    g.adj(u).unwrap().iter().filter(move |v| *v != u)
+}
+
+fn bron_kerbosch<'a, T>(
+    g: &'a Graph<T, Undirected>,
+    clique: &mut Vec<&'a T>,
+    mut candidates: HashSet<&'a T>,
+    mut excluded: HashSet<&'a T>,
+    cliques: &mut Vec<HashSet<T>>,
+) where
+    T: Clone + Hash + Eq + Debug,
+{
+    if candidates.is_empty() {
+        if excluded.is_empty() && !clique.is_empty() {
+            cliques.push(clique.iter().map(|u| (*u).clone()).collect());
+        }
+        return;
+    }
+
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|u| neighbors(g, u).filter(|v| candidates.contains(v)).count())
+        .copied()
+        .unwrap();
+    let pivot_neighbors: HashSet<&T> = neighbors(g, pivot).collect();
+    let branches: Vec<&T> = candidates
+        .iter()
+        .filter(|u| !pivot_neighbors.contains(*u))
+        .copied()
+        .collect();
+
+    for u in branches {
+        let u_neighbors: HashSet<&T> = neighbors(g, u).collect();
+        clique.push(u);
+        bron_kerbosch(
+            g,
+            clique,
+            candidates.intersection(&u_neighbors).copied().collect(),
+            excluded.intersection(&u_neighbors).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(u);
+        excluded.insert(u);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_graph_is_one_clique() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]);
+        assert_eq!(maximal_cliques(&g), vec![HashSet::from([1, 2, 3, 4])]);
+    }
+
+    #[test]
+    fn empty_graph_has_no_cliques() {
+        assert!(maximal_cliques(&Graph::<i8>::new()).is_empty());
+    }
+
+    #[test]
+    fn path_edges_are_cliques() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4)]);
+        g.add_node(5);
+        let actual = maximal_cliques(&g);
+        assert_eq!(actual.len(), 4);
+        for clique in [
+            HashSet::from([1, 2]),
+            HashSet::from([2, 3]),
+            HashSet::from([3, 4]),
+            HashSet::from([5]),
+        ] {
+            assert!(actual.contains(&clique));
+        }
+    }
+
+    #[test]
+    fn cycle_independent_set() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+        let actual = maximum_independent_set(&g);
+        assert_eq!(actual.len(), 2);
+        assert!(actual
+            .iter()
+            .all(|u| actual.iter().all(|v| !g.has_edge(u, v))));
+
+        g.add_node(6);
+        assert_eq!(maximum_independent_set(&g).len(), 3);
+        assert!(maximum_independent_set(&Graph::<i8>::new()).is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+//! Community detection.
+use crate::centrality::edge_betweenness_centrality;
+use crate::components::connected_components;
+use crate::graph::{Graph, Undirected};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Finds communities in an undirected graph using the Girvan–Newman method.
+///
+/// The edge with the highest betweenness is removed repeatedly (recomputing
+/// the betweenness after each removal) until the graph splits into at least
+/// `target_communities` connected components or runs out of edges. Ties
+/// between edges are broken by their order, which requires `T: Ord`.
+pub fn girvan_newman<T>(g: &Graph<T, Undirected>, target_communities: usize) -> Vec<HashSet<T>>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+{
+    let mut h = g.clone();
+    let mut communities = connected_components(&h);
+
+    while communities.len() < target_communities {
+        let betweenness = edge_betweenness_centrality(&h);
+        let edge = betweenness
+            .into_iter()
+            .max_by(|(e1, b1), (e2, b2)| b1.total_cmp(b2).then_with(|| e2.cmp(e1)))
+            .map(|(e, _)| e);
+
+        match edge {
+            Some((u, v)) => {
+                h.remove_edge(&u, &v);
+                communities = connected_components(&h);
+            }
+            None => break,
+        }
+    }
+    communities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn barbell_graph() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (1, 3), (3, 4), (4, 5), (5, 6), (4, 6)]);
+        g
+    }
+
+    #[test]
+    fn girvan_newman_barbell() {
+        let g = barbell_graph();
+        let actual = girvan_newman(&g, 2);
+        assert_eq!(actual.len(), 2);
+        assert!(actual.contains(&HashSet::from([1, 2, 3])));
+        assert!(actual.contains(&HashSet::from([4, 5, 6])));
+        assert_eq!(g.edges::<Vec<_>>().len(), 14);
+    }
+
+    #[test]
+    fn girvan_newman_already_split() {
+        let g = barbell_graph();
+        assert_eq!(girvan_newman(&g, 1).len(), 1);
+    }
+}
@@ -0,0 +1,577 @@
+//! Connected components of a graph.
+use crate::graph::{Directed, Graph, Undirected};
+use crate::random::Rng;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns the connected components of an undirected graph.
+pub fn connected_components<T>(g: &Graph<T, Undirected>) -> Vec<HashSet<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut components: Vec<HashSet<T>> = Vec::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+
+    for node in g.iter() {
+        if !visited.insert(node) {
+            continue;
+        }
+        let mut component: HashSet<T> = HashSet::from([node.clone()]);
+        let mut stack: Vec<&T> = vec![node];
+
+        while let Some(current) = stack.pop() {
+            for neighbor in g.adj(current).expect("No such node in a graph") {
+                if visited.insert(neighbor) {
+                    component.insert(neighbor.clone());
+                    stack.push(neighbor);
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// Returns the number of connected components of an undirected graph.
+pub fn number_connected_components<T>(g: &Graph<T, Undirected>) -> usize
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    connected_components(g).len()
+}
+
+/// Returns edges of a spanning tree of every connected component of an undirected graph,
+/// found with depth-first search. A component with `k` nodes yields a tree with `k - 1` edges.
+pub fn spanning_forest<T>(g: &Graph<T, Undirected>) -> Vec<Vec<(T, T)>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut forest: Vec<Vec<(T, T)>> = Vec::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+
+    for node in g.iter() {
+        if !visited.insert(node) {
+            continue;
+        }
+        let mut tree: Vec<(T, T)> = Vec::new();
+        let mut stack: Vec<&T> = vec![node];
+
+        while let Some(current) = stack.pop() {
+            for neighbor in g.adj(current).expect("No such node in a graph") {
+                if visited.insert(neighbor) {
+                    tree.push((current.clone(), neighbor.clone()));
+                    stack.push(neighbor);
+                }
+            }
+        }
+        forest.push(tree);
+    }
+    forest
+}
+
+/// Returns nodes reachable from `source` following `next` neighbors, including `source`.
+fn reachable<'a, T, F>(source: &'a T, next: F) -> HashSet<&'a T>
+where
+    T: Clone + Hash + Eq + Debug,
+    F: Fn(&T) -> Option<&'a HashSet<T>>,
+{
+    let mut visited: HashSet<&T> = HashSet::from([source]);
+    let mut stack: Vec<&T> = vec![source];
+
+    while let Some(node) = stack.pop() {
+        for neighbor in next(node).into_iter().flatten() {
+            if visited.insert(neighbor) {
+                stack.push(neighbor);
+            }
+        }
+    }
+    visited
+}
+
+/// Returns `true` if every node of a directed graph can reach every other node.
+///
+/// Checks that an arbitrary node reaches all nodes both following edges and
+/// following them backwards. The empty graph is considered strongly connected.
+pub fn is_strongly_connected<T>(g: &Graph<T, Directed>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let n = g.node_count();
+    match g.iter().next() {
+        Some(source) => {
+            reachable(source, |u| g.adj(u)).len() == n
+                && reachable(source, |u| g.pred(u)).len() == n
+        }
+        None => true,
+    }
+}
+
+/// Returns sorted nodes together with the transitive closure of a directed graph as a matrix:
+/// entry `[i][j]` is `true` iff node `j` is reachable from node `i`. Every node reaches itself.
+pub fn reachability_matrix<T>(g: &Graph<T, Directed>) -> (Vec<T>, Vec<Vec<bool>>)
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+{
+    let mut nodes: Vec<T> = g.nodes();
+    nodes.sort();
+    let matrix = nodes
+        .iter()
+        .map(|u| {
+            let reached = reachable(u, |v| g.adj(v));
+            nodes.iter().map(|v| reached.contains(v)).collect()
+        })
+        .collect();
+    (nodes, matrix)
+}
+
+/// Removes a random `fraction` of nodes and returns the size of the largest remaining connected
+/// component.
+///
+/// The choice of nodes is reproducible for a given `seed`. `T: Ord` is required so
+/// that it does not depend on the hash iteration order.
+pub fn largest_component_after_removal<T>(
+    g: &Graph<T, Undirected>,
+    fraction: f64,
+    seed: u64,
+) -> usize
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+{
+    let mut nodes: Vec<T> = g.nodes();
+    nodes.sort();
+    let removed =
+        ((fraction.clamp(0.0, 1.0) * nodes.len() as f64).round() as usize).min(nodes.len());
+    let mut rng = Rng::new(seed);
+    for i in 0..removed {
+        let j = i + rng.gen_index(nodes.len() - i);
+        nodes.swap(i, j);
+    }
+    let kept: HashSet<T> = nodes.into_iter().skip(removed).collect();
+    connected_components(&g.subgraph(&kept))
+        .iter()
+        .map(|c| c.len())
+        .max()
+        .unwrap_or(0)
+}
+
+/// An undirected graph that keeps track of its connected components as edges are added.
+///
+/// Connectivity is maintained with a union-find structure (union by size with
+/// path compression applied whenever the structure is updated), so queries
+/// run in near-constant time.
+#[derive(Debug, Clone)]
+pub struct IncrementalComponents<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    graph: Graph<T, Undirected>,
+    parent: HashMap<T, T>,
+    size: HashMap<T, usize>,
+    count: usize,
+}
+
+impl<T> Default for IncrementalComponents<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IncrementalComponents<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    /// Create an empty structure.
+    pub fn new() -> Self {
+        IncrementalComponents {
+            graph: Graph::new(),
+            parent: HashMap::new(),
+            size: HashMap::new(),
+            count: 0,
+        }
+    }
+
+    /// Create a structure populated from an existing graph.
+    pub fn from_graph(g: Graph<T, Undirected>) -> Self {
+        let mut components = Self::new();
+        for node in g.iter() {
+            components.make_set(node.clone());
+        }
+        for (u, v) in g.edges::<Vec<_>>() {
+            components.union(&u, &v);
+        }
+        components.graph = g;
+        components
+    }
+
+    /// Get the underlying graph.
+    pub fn graph(&self) -> &Graph<T, Undirected> {
+        &self.graph
+    }
+
+    /// Add a node. Do nothing if it already exists.
+    pub fn add_node(&mut self, u: T) {
+        self.make_set(u.clone());
+        self.graph.add_node(u);
+    }
+
+    /// Adds an edge in a graph (u<->v) and merges components of its ends.
+    pub fn add_edge(&mut self, u: T, v: T) {
+        self.make_set(u.clone());
+        self.make_set(v.clone());
+        self.union(&u, &v);
+        self.graph.add_edge(u, v);
+    }
+
+    /// Returns `true` if `u` and `v` are in the same connected component.
+    pub fn connected(&self, u: &T, v: &T) -> bool {
+        match (self.root(u), self.root(v)) {
+            (Some(ru), Some(rv)) => ru == rv,
+            _ => false,
+        }
+    }
+
+    /// Returns the number of connected components.
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+
+    fn make_set(&mut self, u: T) {
+        if !self.parent.contains_key(&u) {
+            self.parent.insert(u.clone(), u.clone());
+            self.size.insert(u, 1);
+            self.count += 1;
+        }
+    }
+
+    fn root(&self, u: &T) -> Option<&T> {
+        let (mut current, _) = self.parent.get_key_value(u)?;
+        loop {
+            let parent = &self.parent[current];
+            if parent == current {
+                return Some(current);
+            }
+            current = parent;
+        }
+    }
+
+    fn find(&mut self, u: &T) -> T {
+        let root = self.root(u).expect("No such node in a graph").clone();
+        let mut current = u.clone();
+        while current != root {
+            let next = self.parent.insert(current, root.clone()).unwrap();
+            current = next;
+        }
+        root
+    }
+
+    fn union(&mut self, u: &T, v: &T) {
+        let (ru, rv) = (self.find(u), self.find(v));
+        if ru == rv {
+            return;
+        }
+        let (large, small) = if self.size[&ru] >= self.size[&rv] {
+            (ru, rv)
+        } else {
+            (rv, ru)
+        };
+        let small_size = self.size.remove(&small).unwrap();
+        *self.size.get_mut(&large).unwrap() += small_size;
+        self.parent.insert(small, large);
+        self.count -= 1;
+    }
+}
+
+/// Returns strongly connected components of a directed graph using Kosaraju's algorithm.
+pub fn strongly_connected_components<T>(g: &Graph<T, Directed>) -> Vec<HashSet<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut finished: Vec<&T> = Vec::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+    for source in g.iter() {
+        if !visited.insert(source) {
+            continue;
+        }
+        let mut stack = vec![(source, g.adj(source).unwrap().iter())];
+        while let Some((node, neighbors)) = stack.last_mut() {
+            let node = *node;
+            match neighbors.next() {
+                Some(next) if visited.insert(next) => {
+                    stack.push((next, g.adj(next).unwrap().iter()))
+                }
+                Some(_) => {}
+                None => {
+                    finished.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    let mut assigned: HashSet<&T> = HashSet::new();
+    let mut components: Vec<HashSet<T>> = Vec::new();
+    for root in finished.into_iter().rev() {
+        if !assigned.insert(root) {
+            continue;
+        }
+        let mut component: HashSet<T> = HashSet::new();
+        let mut stack: Vec<&T> = vec![root];
+        while let Some(node) = stack.pop() {
+            component.insert(node.clone());
+            for prev in g.pred(node).into_iter().flatten() {
+                if assigned.insert(prev) {
+                    stack.push(prev);
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// A directed graph with each strongly connected component contracted to a single node.
+///
+/// Components are numbered from 0 and form a DAG. Reachability between all pairs of
+/// components is precomputed, so queries run in constant time.
+#[derive(Debug, Clone)]
+pub struct Condensation<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    graph: Graph<usize, Directed>,
+    members: Vec<HashSet<T>>,
+    component: HashMap<T, usize>,
+    closure: Vec<Vec<bool>>,
+}
+
+/// Returns the condensation of a directed graph.
+pub fn condensation<T>(g: &Graph<T, Directed>) -> Condensation<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let members = strongly_connected_components(g);
+    let component: HashMap<T, usize> = members
+        .iter()
+        .enumerate()
+        .flat_map(|(i, c)| c.iter().map(move |u| (u.clone(), i)))
+        .collect();
+
+    let mut graph: Graph<usize, Directed> = Graph::new();
+    (0..members.len()).for_each(|i| graph.add_node(i));
+    for (u, v) in g.edges::<Vec<_>>() {
+        if component[&u] != component[&v] {
+            graph.add_edge(component[&u], component[&v]);
+        }
+    }
+    let (_, closure) = reachability_matrix(&graph);
+
+    Condensation {
+        graph,
+        members,
+        component,
+        closure,
+    }
+}
+
+impl<T> Condensation<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    /// Returns the DAG of components.
+    pub fn graph(&self) -> &Graph<usize, Directed> {
+        &self.graph
+    }
+
+    /// Returns nodes of the original graph in component `c`.
+    pub fn members(&self, c: usize) -> Option<&HashSet<T>> {
+        self.members.get(c)
+    }
+
+    /// Returns the component of a `node`, or `None` if it is not in the original graph.
+    pub fn component_of(&self, node: &T) -> Option<usize> {
+        self.component.get(node).copied()
+    }
+
+    /// Returns `true` if `v` is reachable from `u` in the original graph.
+    pub fn can_reach(&self, u: &T, v: &T) -> bool {
+        match (self.component_of(u), self.component_of(v)) {
+            (Some(cu), Some(cv)) => self.closure[cu][cv],
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simple_graph() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6)]);
+        g.add_node(7);
+        g
+    }
+
+    #[test]
+    fn chain_reachability() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(3, 4), (1, 2), (2, 3)]);
+        g.add_node(5);
+        let (nodes, matrix) = reachability_matrix(&g);
+        assert_eq!(nodes, vec![1, 2, 3, 4, 5]);
+        assert!(matrix[0][3]);
+        assert!(matrix[1][2]);
+        assert!(matrix[2][2]);
+        assert!(!matrix[3][0]);
+        assert!(!matrix[0][4]);
+        assert_eq!(matrix[4], vec![false, false, false, false, true]);
+    }
+
+    #[test]
+    fn random_node_removal() {
+        let g = simple_graph();
+        assert_eq!(largest_component_after_removal(&g, 0.0, 42), 6);
+        assert_eq!(largest_component_after_removal(&g, 1.0, 42), 0);
+        let size = largest_component_after_removal(&g, 0.5, 7);
+        assert!(size <= 4);
+        assert_eq!(largest_component_after_removal(&g, 0.5, 7), size);
+    }
+
+    #[test]
+    fn test_spanning_forest() {
+        let mut g = simple_graph();
+        g.add_edges_from(vec![(7, 8), (8, 9), (9, 7)]);
+        let mut forest = spanning_forest(&g);
+        forest.sort_by_key(|tree| tree.len());
+        assert_eq!(
+            forest.iter().map(|t| t.len()).collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+        for tree in forest {
+            assert!(tree.iter().all(|(u, v)| g.has_edge(u, v)));
+            let mut nodes: HashSet<i8> = tree.iter().map(|(u, _)| *u).collect();
+            nodes.extend(tree.iter().map(|(_, v)| *v));
+            assert_eq!(nodes.len(), tree.len() + 1);
+        }
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let g = simple_graph();
+        let actual = connected_components(&g);
+        assert_eq!(actual.len(), 2);
+        assert!(actual.contains(&HashSet::from([1, 2, 3, 4, 5, 6])));
+        assert!(actual.contains(&HashSet::from([7])));
+    }
+
+    #[test]
+    fn strongly_connected_cycle() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1)]);
+        assert!(is_strongly_connected(&g));
+    }
+
+    #[test]
+    fn chain_is_not_strongly_connected() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert!(!is_strongly_connected(&g));
+        g.add_edge(3, 1);
+        g.add_node(4);
+        assert!(!is_strongly_connected(&g));
+    }
+
+    #[test]
+    fn trivial_graphs_are_strongly_connected() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        assert!(is_strongly_connected(&g));
+        g.add_node(1);
+        assert!(is_strongly_connected(&g));
+    }
+
+    #[test]
+    fn incremental_components() {
+        let mut c = IncrementalComponents::from_graph(simple_graph());
+        assert_eq!(c.component_count(), 2);
+        assert!(c.connected(&1, &6));
+        assert!(!c.connected(&1, &7));
+
+        c.add_node(8);
+        c.add_edge(9, 10);
+        assert_eq!(c.component_count(), 4);
+        assert!(!c.connected(&8, &9));
+        assert!(c.connected(&9, &10));
+
+        c.add_edge(7, 8);
+        c.add_edge(10, 7);
+        assert_eq!(c.component_count(), 2);
+        assert!(c.connected(&8, &9));
+
+        c.add_edge(6, 9);
+        assert_eq!(c.component_count(), 1);
+        assert!(c.connected(&1, &8));
+        assert!(!c.connected(&1, &11));
+        assert_eq!(c.graph().nodes::<HashSet<_>>().len(), 10);
+    }
+
+    #[test]
+    fn empty_graph_has_no_components() {
+        let g: Graph<i8> = Graph::new();
+        assert_eq!(number_connected_components(&g), 0);
+    }
+
+    fn directed_graph() -> Graph<i8, Directed> {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 4),
+            (6, 5),
+            (7, 7),
+        ]);
+        g.add_node(8);
+        g
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let actual = strongly_connected_components(&directed_graph());
+        assert_eq!(actual.len(), 5);
+        assert!(actual.contains(&HashSet::from([1, 2, 3])));
+        assert!(actual.contains(&HashSet::from([4, 5])));
+        assert!(actual.contains(&HashSet::from([6])));
+        assert!(actual.contains(&HashSet::from([7])));
+        assert!(actual.contains(&HashSet::from([8])));
+    }
+
+    #[test]
+    fn condensation_reachability() {
+        use crate::search::{SearchAlgorithm, BFS};
+
+        let g = directed_graph();
+        let c = condensation(&g);
+        assert_eq!(c.graph().node_count(), 5);
+        assert_eq!(c.graph().edge_count(), 2);
+        assert_eq!(c.component_of(&1), c.component_of(&3));
+        assert_ne!(c.component_of(&1), c.component_of(&4));
+        assert_eq!(
+            c.members(c.component_of(&4).unwrap()),
+            Some(&HashSet::from([4, 5]))
+        );
+        assert_eq!(c.component_of(&9), None);
+
+        for u in g.iter() {
+            for v in g.iter() {
+                assert_eq!(c.can_reach(u, v), BFS::has_path(&g, *u, *v), "{u} -> {v}");
+            }
+        }
+        assert!(!c.can_reach(&1, &9));
+    }
+}
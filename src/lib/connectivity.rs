@@ -0,0 +1,197 @@
+//! Connectivity between pairs of nodes.
+use crate::graph::{Graph, Undirected};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A flow network over nodes indexed with `usize`.
+struct FlowNetwork {
+    adj: Vec<Vec<usize>>,
+    capacity: HashMap<(usize, usize), usize>,
+}
+
+impl FlowNetwork {
+    fn new(n: usize) -> Self {
+        FlowNetwork {
+            adj: vec![vec![]; n],
+            capacity: HashMap::new(),
+        }
+    }
+
+    fn add_arc(&mut self, u: usize, v: usize, capacity: usize) {
+        if !self.capacity.contains_key(&(u, v)) && !self.capacity.contains_key(&(v, u)) {
+            self.adj[u].push(v);
+            self.adj[v].push(u);
+        }
+        *self.capacity.entry((u, v)).or_default() += capacity;
+        self.capacity.entry((v, u)).or_default();
+    }
+
+    /// Computes the maximum flow from `s` to `t` with the Edmonds–Karp algorithm.
+    fn max_flow(mut self, s: usize, t: usize) -> usize {
+        let mut flow = 0;
+        loop {
+            let mut previous: Vec<Option<usize>> = vec![None; self.adj.len()];
+            let mut queue: VecDeque<usize> = VecDeque::from([s]);
+            previous[s] = Some(s);
+
+            while let Some(u) = queue.pop_front() {
+                for &v in self.adj[u].iter() {
+                    if previous[v].is_none() && self.capacity[&(u, v)] > 0 {
+                        previous[v] = Some(u);
+                        queue.push_back(v);
+                    }
+                }
+            }
+            if previous[t].is_none() {
+                return flow;
+            }
+
+            let mut bottleneck = usize::MAX;
+            let mut v = t;
+            while v != s {
+                let u = previous[v].unwrap();
+                bottleneck = bottleneck.min(self.capacity[&(u, v)]);
+                v = u;
+            }
+            let mut v = t;
+            while v != s {
+                let u = previous[v].unwrap();
+                *self.capacity.get_mut(&(u, v)).unwrap() -= bottleneck;
+                *self.capacity.get_mut(&(v, u)).unwrap() += bottleneck;
+                v = u;
+            }
+            flow += bottleneck;
+        }
+    }
+}
+
+/// Assigns consecutive indices to nodes of a graph.
+fn index_nodes<T>(g: &Graph<T, Undirected>) -> HashMap<&T, usize>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    g.iter().enumerate().map(|(i, n)| (n, i)).collect()
+}
+
+/// Returns the minimum number of nodes whose removal disconnects `source` from `target`.
+///
+/// Computed as a maximum flow in a network where every node is split into an
+/// in- and out-node joined by an arc of unit capacity. An edge between
+/// `source` and `target` counts as a single path. Returns 0 if `source`
+/// equals `target` or either of them is missing from a graph.
+pub fn node_connectivity<T>(g: &Graph<T, Undirected>, source: T, target: T) -> usize
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let index = index_nodes(g);
+    let (Some(&s), Some(&t)) = (index.get(&source), index.get(&target)) else {
+        return 0;
+    };
+    if s == t {
+        return 0;
+    }
+    let mut network = FlowNetwork::new(2 * index.len());
+
+    for (node, &i) in index.iter() {
+        network.add_arc(2 * i, 2 * i + 1, 1);
+        for neighbor in g.adj(node).unwrap() {
+            network.add_arc(2 * i + 1, 2 * index[neighbor], 1);
+        }
+    }
+
+    network.max_flow(2 * s + 1, 2 * t)
+}
+
+/// Returns the minimum number of edges whose removal disconnects `source` from `target`.
+///
+/// Computed as a maximum flow in a network where every edge has unit capacity
+/// in both directions. Returns 0 if `source` equals `target` or either of them
+/// is missing from a graph.
+pub fn edge_connectivity<T>(g: &Graph<T, Undirected>, source: T, target: T) -> usize
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let index = index_nodes(g);
+    let (Some(&s), Some(&t)) = (index.get(&source), index.get(&target)) else {
+        return 0;
+    };
+    if s == t {
+        return 0;
+    }
+    let mut network = FlowNetwork::new(index.len());
+
+    for (node, &i) in index.iter() {
+        for neighbor in g.adj(node).unwrap() {
+            network.add_arc(i, index[neighbor], 1);
+        }
+    }
+
+    network.max_flow(s, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simple_graph() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6)]);
+        g.add_node(7);
+        g
+    }
+
+    #[test]
+    fn edge_connectivity_simple_graph() {
+        let g = simple_graph();
+        assert_eq!(edge_connectivity(&g, 1, 4), 2);
+        assert_eq!(edge_connectivity(&g, 1, 6), 1);
+        assert_eq!(edge_connectivity(&g, 1, 7), 0);
+        assert_eq!(edge_connectivity(&g, 8, 1), 0);
+    }
+
+    #[test]
+    fn edge_connectivity_known_cut() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+            (4, 5),
+            (3, 5),
+        ]);
+        assert_eq!(edge_connectivity(&g, 1, 2), 3);
+        assert_eq!(edge_connectivity(&g, 1, 5), 2);
+    }
+
+    #[test]
+    fn node_connectivity_two_paths() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (1, 4), (4, 3), (2, 5), (5, 4)]);
+        g.add_node(6);
+        assert_eq!(node_connectivity(&g, 1, 3), 2);
+        assert_eq!(node_connectivity(&g, 1, 6), 0);
+        assert_eq!(node_connectivity(&g, 1, 8), 0);
+        assert_eq!(node_connectivity(&g, 8, 8), 0);
+    }
+
+    #[test]
+    fn node_connectivity_cut_vertex() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![
+            (1, 2),
+            (1, 3),
+            (2, 4),
+            (3, 4),
+            (4, 5),
+            (4, 6),
+            (5, 7),
+            (6, 7),
+        ]);
+        assert_eq!(node_connectivity(&g, 1, 7), 1);
+        assert_eq!(node_connectivity(&g, 1, 2), 2);
+    }
+}
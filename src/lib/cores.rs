@@ -0,0 +1,132 @@
+//! Core decomposition of an undirected graph.
+use crate::graph::{Graph, Undirected};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns the core number of every node, i.e. the largest `k` such that the
+/// node belongs to a `k`-core. Self-loops are ignored.
+///
+/// Nodes are peeled off in order of their current minimum degree.
+pub fn core_number<T>(g: &Graph<T, Undirected>) -> HashMap<T, usize>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut degree: HashMap<&T, usize> = g
+        .iter()
+        .map(|u| (u, g.adj(u).unwrap().iter().filter(|v| *v != u).count()))
+        .collect();
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> = BinaryHeap::new();
+    let nodes: Vec<&T> = g.iter().collect();
+    let index: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, u)| (*u, i)).collect();
+    for (i, u) in nodes.iter().enumerate() {
+        heap.push(Reverse((degree[u], i)));
+    }
+
+    let mut core: HashMap<T, usize> = HashMap::new();
+    let mut removed: HashSet<&T> = HashSet::new();
+    let mut k = 0;
+
+    while let Some(Reverse((d, i))) = heap.pop() {
+        let u = nodes[i];
+        if removed.contains(u) || d != degree[u] {
+            continue;
+        }
+        k = k.max(d);
+        core.insert(u.clone(), k);
+        removed.insert(u);
+        for v in g.adj(u).unwrap() {
+            if v != u && !removed.contains(v) {
+                let dv = degree.get_mut(v).unwrap();
+                *dv -= 1;
+                heap.push(Reverse((*dv, index[v])));
+            }
+        }
+    }
+    core
+}
+
+/// Returns the `k`-core of a graph, i.e. the largest induced subgraph in which
+/// every node has degree at least `k`.
+pub fn k_core<T>(g: &Graph<T, Undirected>, k: usize) -> Graph<T, Undirected>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let nodes: HashSet<T> = core_number(g)
+        .into_iter()
+        .filter(|(_, c)| *c >= k)
+        .map(|(u, _)| u)
+        .collect();
+    g.subgraph(&nodes)
+}
+
+/// Returns the subgraph induced on nodes whose degree is within `[min, max]`.
+///
+/// Unlike [`k_core`], degrees are those in the original graph, so the remaining
+/// nodes may have smaller degrees in the result.
+pub fn filter_by_degree<T>(g: &Graph<T, Undirected>, min: usize, max: usize) -> Graph<T, Undirected>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let nodes: HashSet<T> = g
+        .iter()
+        .filter(|u| (min..=max).contains(&g.degree(u)))
+        .cloned()
+        .collect();
+    g.subgraph(&nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn core_with_pendants() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]);
+        g.add_edges_from(vec![(1, 5), (2, 6), (6, 7), (7, 3)]);
+        g.add_node(8);
+        g
+    }
+
+    #[test]
+    fn test_core_number() {
+        let g = core_with_pendants();
+        let expected = HashMap::from([
+            (1, 3),
+            (2, 3),
+            (3, 3),
+            (4, 3),
+            (5, 1),
+            (6, 2),
+            (7, 2),
+            (8, 0),
+        ]);
+        assert_eq!(core_number(&g), expected);
+    }
+
+    #[test]
+    fn test_k_core() {
+        let g = core_with_pendants();
+        let core = k_core(&g, 3);
+        assert_eq!(core.nodes::<HashSet<_>>(), HashSet::from([1, 2, 3, 4]));
+        assert_eq!(core.edge_count(), 6);
+        assert_eq!(k_core(&g, 2).node_count(), 6);
+        assert_eq!(k_core(&g, 0).node_count(), 8);
+        assert_eq!(k_core(&g, 4).node_count(), 0);
+    }
+
+    #[test]
+    fn star_without_leaves() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(0, 1), (0, 2), (0, 3), (3, 4)]);
+        let actual = filter_by_degree(&g, 2, usize::MAX);
+        assert_eq!(actual.nodes::<HashSet<_>>(), HashSet::from([0, 3]));
+        assert_eq!(actual.edge_count(), 1);
+        assert_eq!(actual.degree(&3), 1);
+
+        let hubless = filter_by_degree(&g, 0, 2);
+        assert_eq!(hubless.nodes::<HashSet<_>>(), HashSet::from([1, 2, 3, 4]));
+        assert_eq!(hubless.edge_count(), 1);
+    }
+}
@@ -0,0 +1,277 @@
+//! Cycles in a graph.
+use crate::graph::{Directed, Graph, Undirected};
+use crate::weighted::{Weight, WeightedGraph};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Finds a cycle with the minimum mean edge weight using Karp's algorithm.
+///
+/// Returns the cycle as a sequence of nodes (the edge from the last node back
+/// to the first one closes it) together with its mean weight, or `None` if the
+/// graph has no cycles.
+pub fn min_mean_cycle<T, W>(g: &WeightedGraph<T, Directed, W>) -> Option<(Vec<T>, f64)>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    let nodes: Vec<&T> = g.iter().collect();
+    let index: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let n = nodes.len();
+
+    // dist[k][v] is the minimum weight of a walk with exactly k edges ending at v.
+    let mut dist: Vec<Vec<Option<f64>>> = vec![vec![Some(0.0); n]];
+    let mut parent: Vec<Vec<usize>> = vec![vec![0; n]];
+    for k in 1..=n {
+        let mut level: Vec<Option<f64>> = vec![None; n];
+        let mut level_parent: Vec<usize> = vec![0; n];
+        for (u, node) in nodes.iter().enumerate() {
+            let Some(du) = dist[k - 1][u] else {
+                continue;
+            };
+            for neighbor in g.adj(node).unwrap() {
+                let v = index[neighbor];
+                let d = du + g.weight(node, neighbor).unwrap().to_f64();
+                if level[v].is_none_or(|dv| d < dv) {
+                    level[v] = Some(d);
+                    level_parent[v] = u;
+                }
+            }
+        }
+        dist.push(level);
+        parent.push(level_parent);
+    }
+
+    let mut best: Option<(f64, usize)> = None;
+    for (v, dn) in dist[n].iter().enumerate() {
+        let Some(dn) = *dn else {
+            continue;
+        };
+        let worst = (0..n)
+            .filter_map(|k| dist[k][v].map(|dk| (dn - dk) / (n - k) as f64))
+            .fold(f64::NEG_INFINITY, f64::max);
+        if best.is_none_or(|(mean, _)| worst < mean) {
+            best = Some((worst, v));
+        }
+    }
+    let (_, end) = best?;
+
+    // Every cycle on the minimum walk of n edges to `end` has the minimum mean.
+    let mut walk: Vec<usize> = vec![end];
+    for k in (1..=n).rev() {
+        walk.push(parent[k][*walk.last().unwrap()]);
+    }
+    walk.reverse();
+
+    let mut seen: HashMap<usize, usize> = HashMap::new();
+    for (i, v) in walk.iter().enumerate() {
+        if let Some(j) = seen.insert(*v, i) {
+            let cycle: Vec<T> = walk[j..i].iter().map(|u| nodes[*u].clone()).collect();
+            let total = (0..cycle.len()).fold(0.0, |acc, c| {
+                let next = &cycle[(c + 1) % cycle.len()];
+                acc + g.weight(&cycle[c], next).unwrap().to_f64()
+            });
+            let mean = total / cycle.len() as f64;
+            return Some((cycle, mean));
+        }
+    }
+    None
+}
+
+/// Returns `true` if a directed graph has a cycle of negative total weight anywhere.
+///
+/// Runs the Bellman–Ford algorithm from a virtual source connected to every node
+/// with a zero-weight edge, so cycles unreachable from any particular node are found too.
+pub fn has_negative_cycle<T, W>(g: &WeightedGraph<T, Directed, W>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    let mut dist: HashMap<&T, W> = g.iter().map(|u| (u, W::zero())).collect();
+    // Shortest paths from the virtual source have at most n edges, so they settle
+    // within n rounds unless a negative cycle keeps relaxing them.
+    for _ in 0..=dist.len() {
+        let mut relaxed = false;
+        for u in g.iter() {
+            for v in g.adj(u).unwrap() {
+                let d = dist[u] + g.weight(u, v).unwrap();
+                if d < dist[v] {
+                    dist.insert(v, d);
+                    relaxed = true;
+                }
+            }
+        }
+        if !relaxed {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns a cycle basis of an undirected graph, i.e. a set of independent cycles
+/// which generates every cycle of the graph.
+///
+/// The basis is the fundamental one of a BFS spanning forest: every non-tree edge
+/// closes exactly one cycle. It has `m - n + c` cycles, where `c` is the number of
+/// connected components, but it is not guaranteed to have the minimum total length.
+pub fn minimum_cycle_basis<T>(g: &Graph<T, Undirected>) -> Vec<Vec<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut order: HashMap<&T, usize> = HashMap::new();
+    let mut parent: HashMap<&T, &T> = HashMap::new();
+    let mut depth: HashMap<&T, usize> = HashMap::new();
+    let mut basis: Vec<Vec<T>> = Vec::new();
+
+    for root in g.iter() {
+        if order.contains_key(root) {
+            continue;
+        }
+        order.insert(root, order.len());
+        depth.insert(root, 0);
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(u) = queue.pop_front() {
+            for v in g.adj(u).unwrap() {
+                match order.get(v) {
+                    None => {
+                        order.insert(v, order.len());
+                        parent.insert(v, u);
+                        depth.insert(v, depth[u] + 1);
+                        queue.push_back(v);
+                    }
+                    Some(&i) if u == v || (i < order[u] && parent.get(u) != Some(&v)) => {
+                        basis.push(tree_cycle(&parent, &depth, u, v));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    basis
+}
+
+/// Returns the cycle closed by a non-tree edge `(u, v)` along the tree paths to their common ancestor.
+fn tree_cycle<'a, T>(
+    parent: &HashMap<&'a T, &'a T>,
+    depth: &HashMap<&T, usize>,
+    mut u: &'a T,
+    mut v: &'a T,
+) -> Vec<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut head: Vec<T> = Vec::new();
+    let mut tail: Vec<T> = Vec::new();
+    while depth[u] > depth[v] {
+        head.push(u.clone());
+        u = parent[u];
+    }
+    while depth[v] > depth[u] {
+        tail.push(v.clone());
+        v = parent[v];
+    }
+    while u != v {
+        head.push(u.clone());
+        tail.push(v.clone());
+        u = parent[u];
+        v = parent[v];
+    }
+    head.push(u.clone());
+    head.extend(tail.into_iter().rev());
+    head
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn karp_min_mean_cycle() {
+        let mut g: WeightedGraph<i8, Directed> = WeightedGraph::new();
+        g.add_edge(1, 2, 4.0);
+        g.add_edge(2, 1, 4.0);
+        g.add_edge(2, 3, 1.0);
+        g.add_edge(3, 4, 2.0);
+        g.add_edge(4, 2, 3.0);
+        g.add_edge(4, 5, -10.0);
+
+        let (cycle, mean) = min_mean_cycle(&g).unwrap();
+        assert_eq!(mean, 2.0);
+        assert_eq!(cycle.len(), 3);
+        assert_eq!(
+            cycle.into_iter().collect::<HashSet<_>>(),
+            HashSet::from([2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn karp_self_loop() {
+        let mut g: WeightedGraph<i8, Directed, i32> = WeightedGraph::new();
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+        g.add_edge(3, 3, -2);
+        assert_eq!(min_mean_cycle(&g), Some((vec![3], -2.0)));
+    }
+
+    #[test]
+    fn karp_dag_has_no_cycle() {
+        let mut g: WeightedGraph<i8, Directed> = WeightedGraph::new();
+        g.add_edge(1, 2, 1.0);
+        g.add_edge(2, 3, 1.0);
+        g.add_edge(1, 3, 5.0);
+        assert_eq!(min_mean_cycle(&g), None);
+    }
+
+    #[test]
+    fn unreachable_negative_cycle() {
+        let mut g: WeightedGraph<i8, Directed, i32> = WeightedGraph::new();
+        g.add_weighted_edges_from([(1, 2, 1), (2, 3, 2), (4, 5, 1), (5, 6, -3), (6, 4, 1)]);
+        assert!(has_negative_cycle(&g));
+
+        g.add_edge(6, 4, 2);
+        assert!(!has_negative_cycle(&g));
+        g.add_edge(3, 3, -1);
+        assert!(has_negative_cycle(&g));
+        assert!(!has_negative_cycle(
+            &WeightedGraph::<i8, Directed, i32>::new()
+        ));
+    }
+
+    #[test]
+    fn fundamental_cycle_basis() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 3),
+            (6, 7),
+        ]);
+        g.add_edges_from(vec![(8, 9), (9, 9)]);
+        g.add_node(10);
+
+        let basis = minimum_cycle_basis(&g);
+        assert_eq!(basis.len(), g.edge_count() - g.node_count() + 3);
+        let mut lengths: Vec<_> = basis.iter().map(|c| c.len()).collect();
+        lengths.sort();
+        assert_eq!(lengths, vec![1, 3, 4]);
+        for cycle in basis {
+            let closed = cycle.iter().zip(cycle.iter().cycle().skip(1));
+            assert!(closed.into_iter().all(|(u, v)| g.has_edge(u, v)));
+            assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+        }
+    }
+
+    #[test]
+    fn forest_has_no_cycles() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (4, 5)]);
+        assert!(minimum_cycle_basis(&g).is_empty());
+    }
+}
@@ -0,0 +1,314 @@
+//! Graph measures based on distances between nodes.
+use crate::graph::{Graph, GraphType, Undirected};
+use crate::random::Rng;
+use crate::search::{SearchAlgorithm, BFS};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns the eccentricity of every node, i.e. the maximum distance from it to any other node.
+///
+/// Returns `None` if some node cannot reach all the others (the graph is not
+/// connected, or not strongly connected if directed).
+pub fn eccentricity<T, G>(g: &Graph<T, G>) -> Option<HashMap<T, usize>>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let n = g.node_count();
+    g.iter()
+        .map(|node| {
+            let mut reached = 0;
+            let mut farthest = 0;
+            BFS::search(g, node.clone(), |_, dist| {
+                reached += 1;
+                farthest = dist;
+                false
+            });
+            (reached == n).then(|| (node.clone(), farthest))
+        })
+        .collect()
+}
+
+/// Returns the diameter of a graph, i.e. the maximum eccentricity.
+/// Returns `None` for a disconnected or empty graph.
+pub fn diameter<T, G>(g: &Graph<T, G>) -> Option<usize>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    eccentricity(g)?.into_values().max()
+}
+
+/// Returns the radius of a graph, i.e. the minimum eccentricity.
+/// Returns `None` for a disconnected or empty graph.
+pub fn radius<T, G>(g: &Graph<T, G>) -> Option<usize>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    eccentricity(g)?.into_values().min()
+}
+
+/// Estimates the diameter of a graph as the largest distance found by BFS from `samples`
+/// nodes chosen at random, but reproducibly for a given `seed`. `T: Ord` is required so
+/// that the choice does not depend on the hash iteration order.
+///
+/// The estimate is a lower bound, exact when `samples` is at least the number of nodes
+/// and the graph is connected. Unreachable nodes are ignored.
+pub fn approx_diameter<T, G>(g: &Graph<T, G>, samples: usize, seed: u64) -> usize
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+    G: GraphType,
+{
+    let mut nodes: Vec<&T> = g.iter().collect();
+    nodes.sort();
+    let samples = samples.min(nodes.len());
+    let mut rng = Rng::new(seed);
+    for i in 0..samples {
+        let j = i + rng.gen_index(nodes.len() - i);
+        nodes.swap(i, j);
+    }
+
+    let mut farthest = 0;
+    for node in nodes.into_iter().take(samples) {
+        BFS::search(g, node.clone(), |_, dist| {
+            farthest = farthest.max(dist);
+            false
+        });
+    }
+    farthest
+}
+
+/// Precomputed BFS distances from a few landmark nodes, used to answer approximate distance
+/// queries on a static undirected graph.
+#[derive(Debug, Clone)]
+pub struct Landmarks<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    landmarks: Vec<T>,
+    distances: Vec<HashMap<T, usize>>,
+}
+
+impl<T> Landmarks<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    /// Get the landmark nodes.
+    pub fn landmarks(&self) -> &[T] {
+        &self.landmarks
+    }
+
+    /// Returns lower and upper bounds on the distance between `u` and `v` from the triangle
+    /// inequality, or `None` if no landmark reaches both nodes.
+    pub fn distance_bounds(&self, u: &T, v: &T) -> Option<(usize, usize)> {
+        if u == v {
+            return Some((0, 0));
+        }
+        self.distances
+            .iter()
+            .filter_map(|dist| Some((*dist.get(u)?, *dist.get(v)?)))
+            .map(|(du, dv)| (du.abs_diff(dv), du + dv))
+            .reduce(|(lo, hi), (l, h)| (lo.max(l), hi.min(h)))
+    }
+
+    /// Estimates the distance between `u` and `v` as the shortest detour through a landmark.
+    ///
+    /// The estimate never underestimates and is exact when a landmark lies on a shortest path.
+    /// Returns `usize::MAX` if no landmark reaches both nodes.
+    pub fn estimate_distance(&self, u: &T, v: &T) -> usize {
+        self.distance_bounds(u, v).map_or(usize::MAX, |(_, hi)| hi)
+    }
+}
+
+/// Runs BFS from `count` landmark nodes chosen at random, but reproducibly for a given `seed`.
+/// `T: Ord` is required so that the choice does not depend on the hash iteration order.
+pub fn precompute_landmarks<T>(g: &Graph<T, Undirected>, count: usize, seed: u64) -> Landmarks<T>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+{
+    let mut nodes: Vec<&T> = g.iter().collect();
+    nodes.sort();
+    let count = count.min(nodes.len());
+    let mut rng = Rng::new(seed);
+    for i in 0..count {
+        let j = i + rng.gen_index(nodes.len() - i);
+        nodes.swap(i, j);
+    }
+
+    let landmarks: Vec<T> = nodes.into_iter().take(count).cloned().collect();
+    let distances = landmarks
+        .iter()
+        .map(|landmark| {
+            let mut dist = HashMap::new();
+            BFS::search(g, landmark.clone(), |node, d| {
+                dist.insert(node.clone(), d);
+                false
+            });
+            dist
+        })
+        .collect();
+    Landmarks {
+        landmarks,
+        distances,
+    }
+}
+
+/// Returns nodes whose eccentricity equals `target`.
+fn nodes_with_eccentricity<T>(ecc: HashMap<T, usize>, target: Option<usize>) -> HashSet<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    ecc.into_iter()
+        .filter(|(_, e)| Some(*e) == target)
+        .map(|(n, _)| n)
+        .collect()
+}
+
+/// Returns nodes whose eccentricity equals the radius.
+/// Returns an empty set for a disconnected graph.
+pub fn center<T, G>(g: &Graph<T, G>) -> HashSet<T>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let ecc = eccentricity(g).unwrap_or_default();
+    let radius = ecc.values().min().copied();
+    nodes_with_eccentricity(ecc, radius)
+}
+
+/// Returns nodes whose eccentricity equals the diameter.
+/// Returns an empty set for a disconnected graph.
+pub fn periphery<T, G>(g: &Graph<T, G>) -> HashSet<T>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let ecc = eccentricity(g).unwrap_or_default();
+    let diameter = ecc.values().max().copied();
+    nodes_with_eccentricity(ecc, diameter)
+}
+
+/// Returns the Wiener index of a graph, i.e. the sum of distances over all unordered pairs of nodes.
+/// Returns `None` for a disconnected graph.
+pub fn wiener_index<T>(g: &Graph<T, Undirected>) -> Option<usize>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let n = g.node_count();
+    let mut total = 0;
+    for node in g.iter() {
+        let mut reached = 0;
+        BFS::search(g, node.clone(), |_, dist| {
+            reached += 1;
+            total += dist;
+            false
+        });
+        if reached != n {
+            return None;
+        }
+    }
+    Some(total / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_graph(n: i8) -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from((1..n).map(|i| (i, i + 1)).collect());
+        g
+    }
+
+    #[test]
+    fn path_eccentricity() {
+        let g = path_graph(5);
+        let expected = HashMap::from([(1, 4), (2, 3), (3, 2), (4, 3), (5, 4)]);
+        assert_eq!(eccentricity(&g), Some(expected));
+        assert_eq!(diameter(&g), Some(4));
+        assert_eq!(radius(&g), Some(2));
+    }
+
+    #[test]
+    fn path_center_and_periphery() {
+        assert_eq!(center(&path_graph(5)), HashSet::from([3]));
+        assert_eq!(center(&path_graph(4)), HashSet::from([2, 3]));
+        assert_eq!(periphery(&path_graph(4)), HashSet::from([1, 4]));
+    }
+
+    #[test]
+    fn disconnected_center() {
+        let mut g = path_graph(3);
+        g.add_node(4);
+        assert_eq!(eccentricity(&g), None);
+        assert_eq!(center(&g), HashSet::new());
+        assert_eq!(periphery(&g), HashSet::new());
+    }
+
+    #[test]
+    fn path_wiener_index() {
+        for n in 1..8 {
+            let n_nodes = n as usize;
+            let expected = (n_nodes - 1) * n_nodes * (n_nodes + 1) / 6;
+            assert_eq!(wiener_index(&path_graph(n)), Some(expected));
+        }
+        let mut g = path_graph(3);
+        g.add_node(4);
+        assert_eq!(wiener_index(&g), None);
+    }
+
+    #[test]
+    fn sampled_diameter() {
+        let mut g = path_graph(6);
+        g.add_edges_from(vec![(2, 7), (7, 8)]);
+        let exact = diameter(&g).unwrap();
+        assert_eq!(approx_diameter(&g, 8, 1), exact);
+        assert_eq!(approx_diameter(&g, 100, 2), exact);
+        for seed in 0..10 {
+            assert!(approx_diameter(&g, 2, seed) <= exact);
+            assert_eq!(approx_diameter(&g, 2, seed), approx_diameter(&g, 2, seed));
+        }
+        assert_eq!(approx_diameter(&g, 0, 1), 0);
+    }
+
+    #[test]
+    fn landmark_estimates_bound_exact_distance() {
+        let mut g: Graph<i8> = Graph::new();
+        for i in 0..5 {
+            for j in 0..5 {
+                if i < 4 {
+                    g.add_edge(5 * i + j, 5 * (i + 1) + j);
+                }
+                if j < 4 {
+                    g.add_edge(5 * i + j, 5 * i + j + 1);
+                }
+            }
+        }
+        g.add_edges_from(vec![(30, 31)]);
+        let landmarks = precompute_landmarks(&g, 3, 7);
+        assert_eq!(landmarks.landmarks().len(), 3);
+
+        for u in g.iter() {
+            let mut exact = HashMap::new();
+            BFS::search(&g, *u, |v, d| {
+                exact.insert(*v, d);
+                false
+            });
+            for v in g.iter() {
+                match (exact.get(v), landmarks.distance_bounds(u, v)) {
+                    (Some(d), Some((lo, hi))) => {
+                        assert!(lo <= *d && *d <= hi);
+                        assert_eq!(landmarks.estimate_distance(u, v), hi);
+                        if landmarks.landmarks().contains(u) {
+                            assert_eq!(hi, *d);
+                        }
+                    }
+                    (_, None) => assert_eq!(landmarks.estimate_distance(u, v), usize::MAX),
+                    (None, Some(_)) => panic!("bounds for unreachable nodes"),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Eulerian graphs.
+use crate::graph::{Graph, Undirected};
+use crate::search::{SearchAlgorithm, BFS};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns `true` if all nodes with edges are in one connected component.
+fn edges_connected<T>(g: &Graph<T, Undirected>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut with_edges = g.iter().filter(|u| g.degree(u) > 0);
+    let Some(start) = with_edges.next() else {
+        return true;
+    };
+    let mut reached = 0;
+    BFS::search(g, start.clone(), |_, _| {
+        reached += 1;
+        false
+    });
+    reached == with_edges.count() + 1
+}
+
+/// Returns the number of nodes of odd degree.
+fn odd_degree_count<T>(g: &Graph<T, Undirected>) -> usize
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    g.iter().filter(|u| g.degree(u) % 2 == 1).count()
+}
+
+/// Returns `true` if a graph has an Eulerian circuit, i.e. a closed walk using every edge once.
+///
+/// That is the case iff every node has even degree and all nodes with edges are connected.
+/// Isolated nodes are ignored.
+pub fn is_eulerian<T>(g: &Graph<T, Undirected>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    odd_degree_count(g) == 0 && edges_connected(g)
+}
+
+/// Returns `true` if a graph has an Eulerian path but no Eulerian circuit, i.e. a walk using
+/// every edge once must start and end at different nodes.
+///
+/// That is the case iff exactly two nodes have odd degree and all nodes with edges are connected.
+pub fn is_semi_eulerian<T>(g: &Graph<T, Undirected>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    odd_degree_count(g) == 2 && edges_connected(g)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_is_eulerian() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1), (2, 2)]);
+        g.add_node(5);
+        assert!(is_eulerian(&g));
+        assert!(!is_semi_eulerian(&g));
+
+        g.add_edges_from(vec![(6, 7), (7, 8), (8, 6)]);
+        assert!(!is_eulerian(&g));
+    }
+
+    #[test]
+    fn path_is_semi_eulerian() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4)]);
+        assert!(!is_eulerian(&g));
+        assert!(is_semi_eulerian(&g));
+    }
+
+    #[test]
+    fn star_is_neither() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(0, 1), (0, 2), (0, 3)]);
+        assert!(!is_eulerian(&g));
+        assert!(!is_semi_eulerian(&g));
+    }
+}
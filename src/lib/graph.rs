@@ -1,25 +1,59 @@
 //! Definition of graphs.
 use std::collections::{HashMap, HashSet};
-use std::fmt::Debug;
+use std::fmt;
+use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::marker::PhantomData;
 
 /// An undirected graph type.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Undirected {}
 
 /// A directed graph type.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Directed {}
 
 /// A graph type.
-pub trait GraphType {}
+pub trait GraphType {
+    /// Returns `true` if edges of this graph type are directed.
+    fn is_directed() -> bool;
+}
+
+impl GraphType for Undirected {
+    fn is_directed() -> bool {
+        false
+    }
+}
+
+impl GraphType for Directed {
+    fn is_directed() -> bool {
+        true
+    }
+}
 
-impl GraphType for Undirected {}
-impl GraphType for Directed {}
+/// An error returned when a complement graph would have more edges than allowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplementTooLargeError {
+    /// Number of edges the complement would have.
+    pub edges: usize,
+    /// Maximum number of edges allowed.
+    pub limit: usize,
+}
+
+impl std::error::Error for ComplementTooLargeError {}
+
+impl Display for ComplementTooLargeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "A complement would have {} edges, exceeding the limit of {}",
+            self.edges, self.limit
+        )
+    }
+}
 
 /// A graph object.
-#[derive(Debug)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct Graph<T, G = Undirected>
 where
     T: Clone + Hash + Eq + Debug,
@@ -30,6 +64,31 @@ where
     typ: PhantomData<G>,
 }
 
+impl<T, G> Debug for Graph<T, G>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    /// Lists nodes and edges, with every undirected edge listed once.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut seen: HashSet<&T> = HashSet::new();
+        let mut edges: Vec<(&T, &T)> = Vec::new();
+        for (u, neighbors) in self.adj.iter() {
+            seen.insert(u);
+            for v in neighbors {
+                if G::is_directed() || u == v || !seen.contains(v) {
+                    edges.push((u, v));
+                }
+            }
+        }
+        f.debug_struct("Graph")
+            .field("directed", &G::is_directed())
+            .field("nodes", &self.adj.keys().collect::<Vec<_>>())
+            .field("edges", &edges)
+            .finish()
+    }
+}
+
 /// An alias for a directed graph.
 pub type DiGraph<T> = Graph<T, Directed>;
 
@@ -57,16 +116,47 @@ where
         }
     }
 
+    /// Returns `true` if a graph is directed.
+    pub fn is_directed(&self) -> bool {
+        G::is_directed()
+    }
+
     /// Iterate over a graph, i.e. over its keys.
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.adj.keys()
     }
 
+    /// Iterate over nodes together with their neighbors, without cloning.
+    pub fn adjacency(&self) -> impl Iterator<Item = (&T, &HashSet<T>)> {
+        self.adj.iter()
+    }
+
+    /// Get a node as stored in a graph.
+    pub(crate) fn node_ref(&self, u: &T) -> Option<&T> {
+        self.adj.get_key_value(u).map(|(k, _)| k)
+    }
+
     /// Get all nodes from a graph.
     pub fn nodes<B: FromIterator<T>>(&self) -> B {
         self.adj.keys().cloned().collect()
     }
 
+    /// Returns the number of nodes in a graph.
+    pub fn node_count(&self) -> usize {
+        self.adj.len()
+    }
+
+    /// Returns the number of edges in a graph. An undirected edge is counted once.
+    pub fn edge_count(&self) -> usize {
+        let arcs: usize = self.adj.values().map(|v| v.len()).sum();
+        if G::is_directed() {
+            arcs
+        } else {
+            let self_loops = self.adj.iter().filter(|(u, v)| v.contains(u)).count();
+            (arcs + self_loops) / 2
+        }
+    }
+
     pub fn edges<B: FromIterator<(T, T)>>(&self) -> B {
         self.adj
             .clone()
@@ -75,6 +165,192 @@ where
             .collect()
     }
 
+    /// Returns the subgraph induced on `nodes`, i.e. with all edges among them.
+    /// Nodes missing from a graph are ignored.
+    pub fn subgraph(&self, nodes: &HashSet<T>) -> Self {
+        let induced = |map: &HashMap<T, HashSet<T>>| -> HashMap<T, HashSet<T>> {
+            map.iter()
+                .filter(|(u, _)| nodes.contains(*u))
+                .map(|(u, vs)| {
+                    let vs = vs.iter().filter(|v| nodes.contains(*v)).cloned().collect();
+                    (u.clone(), vs)
+                })
+                .collect()
+        };
+        Graph {
+            adj: induced(&self.adj),
+            pred: induced(&self.pred),
+            typ: PhantomData,
+        }
+    }
+
+    /// Get all edges sorted, with every undirected edge listed once as `(u, v)` with `u <= v`.
+    pub fn edges_sorted(&self) -> Vec<(T, T)>
+    where
+        T: Ord,
+    {
+        let mut edges: Vec<(T, T)> = self
+            .edges::<Vec<_>>()
+            .into_iter()
+            .filter(|(u, v)| G::is_directed() || u <= v)
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    /// Add a node. Do nothing if it already exists.
+    pub fn add_node(&mut self, u: T) {
+        if G::is_directed() {
+            self.pred.entry(u.clone()).or_default();
+        }
+        self.adj.entry(u).or_default();
+    }
+
+    /// Adds an edge in a graph, i.e. u<->v if undirected or u->v if directed.
+    pub fn add_edge(&mut self, u: T, v: T) {
+        if G::is_directed() {
+            self.add_directed_edge(u.clone(), v.clone());
+            self.adj.entry(v.clone()).or_default();
+
+            self.pred.entry(v).or_default().insert(u.clone());
+            self.pred.entry(u).or_default();
+        } else {
+            self.add_directed_edge(u.clone(), v.clone());
+            self.add_directed_edge(v, u);
+        }
+    }
+
+    /// Add many edges at once
+    pub fn add_edges_from(&mut self, edges: Vec<(T, T)>) {
+        for edge in edges.into_iter() {
+            self.add_edge(edge.0, edge.1);
+        }
+    }
+
+    /// Renames a node `old` to `new` keeping all its edges. Returns `false` and does nothing
+    /// if `old` does not exist or `new` already does.
+    pub fn relabel_node(&mut self, old: &T, new: T) -> bool {
+        if !self.adj.contains_key(old) || self.adj.contains_key(&new) {
+            return false;
+        }
+        let rename = |set: &mut HashSet<T>| {
+            if set.remove(old) {
+                set.insert(new.clone());
+            }
+        };
+
+        let mut successors = self.adj.remove(old).unwrap();
+        rename(&mut successors);
+        let inverse = if G::is_directed() {
+            &mut self.pred
+        } else {
+            &mut self.adj
+        };
+        for v in successors.iter() {
+            if let Some(set) = inverse.get_mut(v) {
+                rename(set);
+            }
+        }
+        if let Some(mut predecessors) = self.pred.remove(old) {
+            rename(&mut predecessors);
+            for v in predecessors.iter() {
+                if let Some(set) = self.adj.get_mut(v) {
+                    rename(set);
+                }
+            }
+            self.pred.insert(new.clone(), predecessors);
+        }
+        self.adj.insert(new, successors);
+        true
+    }
+
+    /// Returns the complement of a graph, i.e. a graph on the same nodes with an edge between
+    /// every two distinct nodes which are not adjacent. It has no self-loops.
+    pub fn complement(&self) -> Self {
+        let mut g: Self = Graph::new();
+        for (u, neighbors) in self.adj.iter() {
+            g.add_node(u.clone());
+            for v in self.adj.keys() {
+                if u != v && !neighbors.contains(v) {
+                    g.add_edge(u.clone(), v.clone());
+                }
+            }
+        }
+        g
+    }
+
+    /// Returns the complement of a graph like [`Graph::complement`], unless it would have more
+    /// than `max_edges_per_node` edges per node. Complements of large sparse graphs are nearly
+    /// complete, so this guards against exhausting memory.
+    pub fn try_complement(
+        &self,
+        max_edges_per_node: usize,
+    ) -> Result<Self, ComplementTooLargeError> {
+        let n = self.node_count();
+        let self_loops = self.adj.iter().filter(|(u, v)| v.contains(u)).count();
+        let ordered_pairs = n * n.saturating_sub(1);
+        let pairs = if G::is_directed() {
+            ordered_pairs
+        } else {
+            ordered_pairs / 2
+        };
+        let edges = pairs - (self.edge_count() - self_loops);
+        let limit = max_edges_per_node.saturating_mul(n);
+        if edges > limit {
+            return Err(ComplementTooLargeError { edges, limit });
+        }
+        Ok(self.complement())
+    }
+
+    /// Returns the quotient graph of a partition of nodes into blocks of an equivalence relation
+    /// `same_block`. Blocks are numbered from 0, and two blocks are adjacent iff there is an edge
+    /// between their members. Edges within a block are dropped.
+    pub fn quotient_graph<F>(&self, same_block: F) -> Graph<usize, G>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut representatives: Vec<&T> = Vec::new();
+        let mut block: HashMap<&T, usize> = HashMap::new();
+        for u in self.adj.keys() {
+            let b = match representatives.iter().position(|r| same_block(r, u)) {
+                Some(b) => b,
+                None => {
+                    representatives.push(u);
+                    representatives.len() - 1
+                }
+            };
+            block.insert(u, b);
+        }
+
+        let mut g: Graph<usize, G> = Graph::new();
+        (0..representatives.len()).for_each(|b| g.add_node(b));
+        for (u, neighbors) in self.adj.iter() {
+            for v in neighbors {
+                if block[u] != block[v] {
+                    g.add_edge(block[u], block[v]);
+                }
+            }
+        }
+        g
+    }
+
+    /// Replaces an edge between `u` and `v` with a path through a new node `w`. Returns `false`
+    /// and does nothing if there is no such edge or `w` already exists.
+    pub fn subdivide_edge(&mut self, u: &T, v: &T, w: T) -> bool {
+        if !self.has_edge(u, v) || self.adj.contains_key(&w) {
+            return false;
+        }
+        self.adj.get_mut(u).unwrap().remove(v);
+        if G::is_directed() {
+            self.pred.get_mut(v).unwrap().remove(u);
+        } else {
+            self.adj.get_mut(v).unwrap().remove(u);
+        }
+        self.add_edge(u.clone(), w.clone());
+        self.add_edge(w, v.clone());
+        true
+    }
+
     /// Adds a directed edge from u to v (u->v).
     fn add_directed_edge(&mut self, u: T, v: T) {
         self.adj.entry(u).or_default().insert(v);
@@ -84,31 +360,164 @@ where
     pub fn adj(&self, u: &T) -> Option<&HashSet<T>> {
         self.adj.get(u)
     }
+
+    /// Get adjacent elements of a node in ascending order, e.g. for reproducible traversals.
+    /// Returns an empty vector if the node does not exist.
+    pub fn neighbors_sorted(&self, u: &T) -> Vec<&T>
+    where
+        T: Ord,
+    {
+        let mut neighbors: Vec<&T> = self.adj.get(u).into_iter().flatten().collect();
+        neighbors.sort();
+        neighbors
+    }
+
+    /// Returns the number of neighbors (successors if directed) of a node, or 0 if it does not exist.
+    pub fn degree_or_zero(&self, u: &T) -> usize {
+        self.adj.get(u).map_or(0, |v| v.len())
+    }
+
+    /// Iterate over common neighbors (successors if directed) of `u` and `v`. Iterates over the
+    /// smaller set of neighbors and looks each of them up in the larger one.
+    pub fn neighbors_intersection<'a>(&'a self, u: &T, v: &T) -> impl Iterator<Item = &'a T> {
+        let pair = self.adj.get(u).zip(self.adj.get(v));
+        pair.into_iter().flat_map(|(a, b)| {
+            let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+            small.iter().filter(move |w| large.contains(*w))
+        })
+    }
+
+    /// Iterate over edges touching `u`: `(u, v)` pairs for out-edges, or any edges if undirected,
+    /// followed by `(v, u)` pairs for in-edges. A self-loop is listed once.
+    pub fn incident_edges<'a>(&'a self, u: &T) -> impl Iterator<Item = (&'a T, &'a T)> {
+        let node = self.node_ref(u);
+        let out_edges = node
+            .into_iter()
+            .flat_map(move |u| self.adj[u].iter().map(move |v| (u, v)));
+        let in_edges = node.into_iter().flat_map(move |u| {
+            self.pred
+                .get(u)
+                .into_iter()
+                .flatten()
+                .filter(move |v| *v != u)
+                .map(move |v| (v, u))
+        });
+        out_edges.chain(in_edges)
+    }
+
+    /// Get all nodes without any adjacent edges.
+    pub fn isolates<B: FromIterator<T>>(&self) -> B {
+        self.adj
+            .iter()
+            .filter(|(u, v)| v.is_empty() && self.pred.get(*u).is_none_or(|p| p.is_empty()))
+            .map(|(u, _)| u.clone())
+            .collect()
+    }
+
+    /// Returns the number of nodes without any adjacent edges.
+    pub fn number_of_isolates(&self) -> usize {
+        self.isolates::<Vec<_>>().len()
+    }
+
+    /// Returns `true` if there is an edge from u to v.
+    pub fn has_edge(&self, u: &T, v: &T) -> bool {
+        self.adj
+            .get(u)
+            .is_some_and(|neighbors| neighbors.contains(v))
+    }
+
+    /// Returns `true` if every node and every edge of a graph is in `other`, with the same labels.
+    pub fn is_subgraph_of(&self, other: &Graph<T, G>) -> bool {
+        self.adj.iter().all(|(u, neighbors)| {
+            other
+                .adj
+                .get(u)
+                .is_some_and(|theirs| neighbors.is_subset(theirs))
+        })
+    }
+
+    /// Checks many edges at once, returning whether each of them exists in input order.
+    pub fn has_edges<I: IntoIterator<Item = (T, T)>>(&self, edges: I) -> Vec<bool> {
+        edges
+            .into_iter()
+            .map(|(u, v)| self.has_edge(&u, &v))
+            .collect()
+    }
+
+    /// Iterate over adjacent elements of `u` skipping those in `excluded`.
+    pub fn neighbors_excluding<'a>(
+        &'a self,
+        u: &T,
+        excluded: &'a HashSet<T>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.adj
+            .get(u)
+            .into_iter()
+            .flatten()
+            .filter(move |v| !excluded.contains(*v))
+    }
 }
 
 impl<T> Graph<T, Undirected>
 where
     T: Clone + Hash + Eq + Debug,
 {
-    pub fn is_directed(&self) -> bool {
-        false
+    /// Removes an edge (u<->v). Returns `false` if there was no such edge.
+    pub fn remove_edge(&mut self, u: &T, v: &T) -> bool {
+        let removed = match self.adj.get_mut(u) {
+            Some(neighbors) => neighbors.remove(v),
+            None => false,
+        };
+        if removed {
+            if let Some(neighbors) = self.adj.get_mut(v) {
+                neighbors.remove(u);
+            }
+        }
+        removed
     }
 
-    /// Add a node. Do nothing if it already exists.
-    pub fn add_node(&mut self, u: T) {
-        self.adj.entry(u).or_default();
+    /// Returns the number of edges adjacent to a node, counting a self-loop twice.
+    pub fn degree(&self, u: &T) -> usize {
+        match self.adj.get(u) {
+            Some(v) => v.len() + usize::from(v.contains(u)),
+            _ => 0,
+        }
     }
 
-    /// Adds an edge in a graph (u<->v).
-    pub fn add_edge(&mut self, u: T, v: T) {
-        self.add_directed_edge(u.clone(), v.clone());
-        self.add_directed_edge(v, u);
+    /// Returns a directed graph with both edges u->v and v->u for every edge u<->v.
+    pub fn to_directed(&self) -> Graph<T, Directed> {
+        let mut g: Graph<T, Directed> = Graph::new();
+        for (u, neighbors) in self.adj.iter() {
+            g.add_node(u.clone());
+            for v in neighbors.iter() {
+                g.add_edge(u.clone(), v.clone());
+            }
+        }
+        g
     }
 
-    /// Add many edges at once
-    pub fn add_edges_from(&mut self, edges: Vec<(T, T)>) {
+    /// Removes a node and all edges adjacent to it. Do nothing if it does not exist.
+    pub fn remove_node(&mut self, u: &T) {
+        if let Some(neighbors) = self.adj.remove(u) {
+            for v in neighbors.iter() {
+                if let Some(adj) = self.adj.get_mut(v) {
+                    adj.remove(u);
+                }
+            }
+        }
+    }
+
+    /// Remove many nodes at once, ignoring absent ones.
+    pub fn remove_nodes_from<I: IntoIterator<Item = T>>(&mut self, nodes: I) {
+        for node in nodes.into_iter() {
+            self.remove_node(&node);
+        }
+    }
+
+    /// Remove many edges at once, ignoring absent ones.
+    pub fn remove_edges_from<I: IntoIterator<Item = (T, T)>>(&mut self, edges: I) {
         for edge in edges.into_iter() {
-            self.add_edge(edge.0, edge.1);
+            self.remove_edge(&edge.0, &edge.1);
         }
     }
 }
@@ -117,32 +526,70 @@ impl<T> Graph<T, Directed>
 where
     T: Clone + Hash + Eq + Debug,
 {
-    pub fn is_directed(&self) -> bool {
-        true
+    /// Removes an edge (u->v). Returns `false` if there was no such edge.
+    pub fn remove_edge(&mut self, u: &T, v: &T) -> bool {
+        let removed = match self.adj.get_mut(u) {
+            Some(neighbors) => neighbors.remove(v),
+            None => false,
+        };
+        if removed {
+            if let Some(predecessors) = self.pred.get_mut(v) {
+                predecessors.remove(u);
+            }
+        }
+        removed
     }
 
-    /// Add a node. Do nothing if it already exists.
-    pub fn add_node(&mut self, u: T) {
-        self.adj.entry(u.clone()).or_default();
-        self.pred.entry(u).or_default();
+    /// Removes a node and all edges adjacent to it. Do nothing if it does not exist.
+    pub fn remove_node(&mut self, u: &T) {
+        if let Some(successors) = self.adj.remove(u) {
+            for v in successors.iter() {
+                if let Some(pred) = self.pred.get_mut(v) {
+                    pred.remove(u);
+                }
+            }
+        }
+        if let Some(predecessors) = self.pred.remove(u) {
+            for v in predecessors.iter() {
+                if let Some(adj) = self.adj.get_mut(v) {
+                    adj.remove(u);
+                }
+            }
+        }
     }
 
-    /// Adds an edge in a graph (u->v).
-    pub fn add_edge(&mut self, u: T, v: T) {
-        self.adj.entry(u.clone()).or_default().insert(v.clone());
-        self.adj.entry(v.clone()).or_default();
-
-        self.pred.entry(v).or_default().insert(u.clone());
-        self.pred.entry(u).or_default();
+    /// Remove many nodes at once, ignoring absent ones.
+    pub fn remove_nodes_from<I: IntoIterator<Item = T>>(&mut self, nodes: I) {
+        for node in nodes.into_iter() {
+            self.remove_node(&node);
+        }
     }
 
-    /// Add many edges at once
-    pub fn add_edges_from(&mut self, edges: Vec<(T, T)>) {
+    /// Remove many edges at once, ignoring absent ones.
+    pub fn remove_edges_from<I: IntoIterator<Item = (T, T)>>(&mut self, edges: I) {
         for edge in edges.into_iter() {
-            self.add_edge(edge.0, edge.1);
+            self.remove_edge(&edge.0, &edge.1);
         }
     }
 
+    /// Returns an undirected graph with an edge u<->v for every edge u->v.
+    /// Reciprocal edges u->v and v->u are merged into one.
+    pub fn to_undirected(&self) -> Graph<T, Undirected> {
+        let mut g: Graph<T, Undirected> = Graph::new();
+        for (u, neighbors) in self.adj.iter() {
+            g.add_node(u.clone());
+            for v in neighbors.iter() {
+                g.add_edge(u.clone(), v.clone());
+            }
+        }
+        g
+    }
+
+    /// Get predecessors of an element in a directed graph.
+    pub fn pred(&self, u: &T) -> Option<&HashSet<T>> {
+        self.pred.get(u)
+    }
+
     pub fn in_degree(&self, u: &T) -> usize {
         match self.pred.get(u) {
             Some(v) => v.len(),
@@ -186,6 +633,457 @@ mod tests {
         assert_eq!(*g.adj(&2).unwrap(), HashSet::from([1]));
     }
 
+    fn directedness<T, G>(g: &Graph<T, G>) -> bool
+    where
+        T: Clone + Hash + Eq + Debug,
+        G: GraphType,
+    {
+        g.is_directed()
+    }
+
+    #[test]
+    fn adjacency() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        g.add_node(4);
+        let actual: HashMap<&i8, &HashSet<i8>> = g.adjacency().collect();
+        let expected = HashMap::from([
+            (1, HashSet::from([2])),
+            (2, HashSet::from([1, 3])),
+            (3, HashSet::from([2])),
+            (4, HashSet::new()),
+        ]);
+        assert_eq!(actual, expected.iter().collect());
+    }
+
+    fn build_triangle<G: GraphType>(g: &mut Graph<i8, G>) {
+        g.add_node(0);
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        g.add_edge(3, 1);
+    }
+
+    #[test]
+    fn generic_construction() {
+        let mut g: Graph<i8> = Graph::new();
+        build_triangle(&mut g);
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        build_triangle(&mut dg);
+
+        assert_eq!(g.node_count(), 4);
+        assert_eq!(dg.node_count(), 4);
+        assert_eq!(g.edge_count(), 3);
+        assert_eq!(dg.edge_count(), 3);
+        assert!(g.has_edge(&1, &3));
+        assert!(dg.has_edge(&3, &1));
+        assert!(!dg.has_edge(&1, &3));
+        assert_eq!(dg.pred(&1), Some(&HashSet::from([3])));
+        assert_eq!(dg.pred(&0), Some(&HashSet::new()));
+    }
+
+    #[test]
+    fn degree_or_zero() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3)]);
+        g.add_node(4);
+        assert_eq!(g.degree_or_zero(&1), 2);
+        assert_eq!(g.degree_or_zero(&4), 0);
+        assert_eq!(g.degree_or_zero(&5), 0);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (1, 3)]);
+        assert_eq!(dg.degree_or_zero(&1), 2);
+        assert_eq!(dg.degree_or_zero(&2), 0);
+    }
+
+    #[test]
+    fn debug_format() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edge(1, 1);
+        assert_eq!(
+            format!("{:?}", g),
+            "Graph { directed: false, nodes: [1], edges: [(1, 1)] }"
+        );
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edge(1, 1);
+        assert_eq!(
+            format!("{:?}", dg),
+            "Graph { directed: true, nodes: [1], edges: [(1, 1)] }"
+        );
+
+        g.add_edge(1, 2);
+        let debug = format!("{:?}", g);
+        assert!(debug.contains("directed: false"));
+        assert_eq!(
+            debug.matches("(1, 2)").count() + debug.matches("(2, 1)").count(),
+            1
+        );
+    }
+
+    /// A node counting how many times it is hashed.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Probe(u16);
+
+    thread_local! {
+        static HASHED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    impl Hash for Probe {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            HASHED.with(|h| h.set(h.get() + 1));
+            self.0.hash(state);
+        }
+    }
+
+    #[test]
+    fn neighbors_intersection() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (1, 4), (5, 3), (5, 4), (5, 6)]);
+        let common: HashSet<_> = g.neighbors_intersection(&1, &5).collect();
+        assert_eq!(common, HashSet::from([&3, &4]));
+        assert_eq!(g.neighbors_intersection(&1, &9).count(), 0);
+
+        let mut g: Graph<Probe> = Graph::new();
+        g.add_edges_from(vec![(Probe(0), Probe(1)), (Probe(0), Probe(2))]);
+        g.add_edges_from((2..200).map(|i| (Probe(1000), Probe(i))).collect());
+        let before = HASHED.with(|h| h.get());
+        let common: Vec<_> = g.neighbors_intersection(&Probe(1000), &Probe(0)).collect();
+        assert_eq!(common, vec![&Probe(2)]);
+        assert!(HASHED.with(|h| h.get()) - before < 10);
+    }
+
+    #[test]
+    fn is_subgraph_of() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1), (3, 4)]);
+        let mut tree: Graph<i8> = Graph::new();
+        tree.add_edges_from(vec![(1, 2), (3, 1), (4, 3)]);
+        assert!(tree.is_subgraph_of(&g));
+        assert!(g.is_subgraph_of(&g));
+        assert!(!g.is_subgraph_of(&tree));
+
+        tree.add_edge(2, 4);
+        assert!(!tree.is_subgraph_of(&g));
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 3)]);
+        let mut reversed: DiGraph<i8> = DiGraph::new();
+        reversed.add_edge(2, 1);
+        assert!(!reversed.is_subgraph_of(&dg));
+    }
+
+    #[test]
+    fn incident_edges() {
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (3, 1), (1, 1), (2, 3), (4, 1)]);
+        let actual: HashSet<_> = dg.incident_edges(&1).collect();
+        let expected = HashSet::from([(&1, &2), (&3, &1), (&1, &1), (&4, &1)]);
+        assert_eq!(actual, expected);
+        assert_eq!(dg.incident_edges(&1).count(), 4);
+        assert_eq!(dg.incident_edges(&5).count(), 0);
+
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (3, 1), (1, 1)]);
+        let actual: HashSet<_> = g.incident_edges(&1).collect();
+        assert_eq!(actual, HashSet::from([(&1, &2), (&1, &3), (&1, &1)]));
+        assert_eq!(g.incident_edges(&1).count(), 3);
+    }
+
+    #[test]
+    fn relabel_node() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (1, 1), (2, 3)]);
+        assert!(g.relabel_node(&1, 9));
+        assert!(!g.relabel_node(&1, 8));
+        assert!(!g.relabel_node(&2, 3));
+        assert_eq!(g.edges_sorted(), vec![(2, 3), (2, 9), (3, 9), (9, 9)]);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (3, 1), (1, 1), (2, 3)]);
+        assert!(dg.relabel_node(&1, 9));
+        assert_eq!(dg.edges_sorted(), vec![(2, 3), (3, 9), (9, 2), (9, 9)]);
+        assert_eq!(dg.pred(&9), Some(&HashSet::from([3, 9])));
+        assert_eq!(dg.pred(&2), Some(&HashSet::from([9])));
+        assert_eq!(dg.pred(&1), None);
+    }
+
+    #[test]
+    fn complement() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 3)]);
+        g.add_node(4);
+        assert_eq!(
+            g.complement().edges_sorted(),
+            vec![(1, 3), (1, 4), (2, 4), (3, 4)]
+        );
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert_eq!(
+            dg.complement().edges_sorted(),
+            vec![(1, 3), (2, 1), (3, 1), (3, 2)]
+        );
+        assert_eq!(dg.complement().pred(&1), Some(&HashSet::from([2, 3])));
+    }
+
+    #[test]
+    fn try_complement() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 3)]);
+        g.add_node(4);
+        assert_eq!(g.try_complement(1), Ok(g.complement()));
+        assert_eq!(
+            g.try_complement(0),
+            Err(ComplementTooLargeError { edges: 4, limit: 0 })
+        );
+
+        let mut path: Graph<u32> = Graph::new();
+        path.add_edges_from((0..2000).map(|i| (i, i + 1)).collect());
+        let err = path.try_complement(100).unwrap_err();
+        assert_eq!(err.edges, 2001 * 2000 / 2 - 2000);
+        assert_eq!(err.limit, 200_100);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert_eq!(
+            dg.try_complement(1),
+            Err(ComplementTooLargeError { edges: 4, limit: 3 })
+        );
+        assert_eq!(dg.try_complement(2), Ok(dg.complement()));
+    }
+
+    #[test]
+    fn quotient_graph() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+        let parity = g.quotient_graph(|u, v| u % 2 == v % 2);
+        assert_eq!(parity.edges_sorted(), vec![(0, 1)]);
+
+        let thirds = g.quotient_graph(|u, v| u % 3 == v % 3);
+        assert_eq!(thirds.edges_sorted(), vec![(0, 1), (0, 2), (1, 2)]);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 4), (3, 5)]);
+        let parity = dg.quotient_graph(|u, v| u % 2 == v % 2);
+        assert_eq!(parity.node_count(), 2);
+        assert_eq!(parity.edge_count(), 1);
+    }
+
+    #[test]
+    fn subdivide_edge() {
+        use crate::search::{SearchAlgorithm, BFS};
+
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert!(g.subdivide_edge(&2, &1, 4));
+        assert!(!g.subdivide_edge(&1, &2, 5));
+        assert!(!g.subdivide_edge(&2, &3, 4));
+        assert_eq!(BFS::shortest_path(&g, 1, 3), Some(vec![1, 4, 2, 3]));
+        assert_eq!(g.edge_count(), 3);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert!(!dg.subdivide_edge(&2, &1, 4));
+        assert!(dg.subdivide_edge(&1, &2, 4));
+        assert_eq!(BFS::shortest_path_length(&dg, 1, 3), Some(3));
+        assert_eq!(dg.pred(&2), Some(&HashSet::from([4])));
+    }
+
+    #[test]
+    fn is_directed() {
+        let g: Graph<i8> = Graph::new();
+        let dg: DiGraph<i8> = DiGraph::new();
+        assert!(!directedness(&g));
+        assert!(directedness(&dg));
+        assert!(!Undirected::is_directed());
+        assert!(Directed::is_directed());
+    }
+
+    #[test]
+    fn degree() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (3, 3)]);
+        assert_eq!(g.degree(&1), 2);
+        assert_eq!(g.degree(&2), 1);
+        assert_eq!(g.degree(&3), 3);
+        assert_eq!(g.degree(&4), 0);
+    }
+
+    #[test]
+    fn edges_sorted() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6)]);
+        g.add_node(7);
+        let expected = vec![(1, 2), (1, 5), (2, 3), (3, 4), (4, 5), (4, 6)];
+        assert_eq!(g.edges_sorted(), expected);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(3, 1), (1, 3), (2, 1), (1, 1)]);
+        assert_eq!(dg.edges_sorted(), vec![(1, 1), (1, 3), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn neighbors_sorted() {
+        let preorder = |g: &Graph<i8>| {
+            let mut visited: HashSet<i8> = HashSet::new();
+            let mut stack = vec![1];
+            let mut order = vec![];
+            while let Some(u) = stack.pop() {
+                if visited.insert(u) {
+                    order.push(u);
+                    stack.extend(g.neighbors_sorted(&u).into_iter().rev());
+                }
+            }
+            order
+        };
+        let edges = vec![(1, 5), (1, 3), (1, 2), (2, 4), (3, 4), (4, 6), (5, 6)];
+        for _ in 0..10 {
+            let mut g: Graph<i8> = Graph::new();
+            g.add_edges_from(edges.clone());
+            assert_eq!(g.neighbors_sorted(&1), vec![&2, &3, &5]);
+            assert_eq!(g.neighbors_sorted(&4), vec![&2, &3, &6]);
+            assert!(g.neighbors_sorted(&7).is_empty());
+            assert_eq!(preorder(&g), vec![1, 2, 4, 3, 6, 5]);
+        }
+    }
+
+    #[test]
+    fn node_and_edge_count() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 3)]);
+        g.add_node(4);
+        assert_eq!(g.node_count(), 4);
+        assert_eq!(g.edge_count(), 3);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 1), (3, 3)]);
+        assert_eq!(dg.node_count(), 3);
+        assert_eq!(dg.edge_count(), 3);
+    }
+
+    #[test]
+    fn subgraph() {
+        let mut g: DiGraph<i8> = DiGraph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1), (3, 4)]);
+        let h = g.subgraph(&HashSet::from([1, 3, 4, 5]));
+        assert_eq!(h.nodes::<HashSet<_>>(), HashSet::from([1, 3, 4]));
+        assert_eq!(h.edges::<HashSet<_>>(), HashSet::from([(3, 1), (3, 4)]));
+        assert_eq!(h.in_degree_map(), HashMap::from([(1, 1), (3, 0), (4, 1)]));
+    }
+
+    #[test]
+    fn to_directed() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        g.add_node(4);
+        let dg = g.to_directed();
+        assert_eq!(dg.nodes::<HashSet<_>>(), HashSet::from([1, 2, 3, 4]));
+        assert_eq!(
+            dg.edges::<HashSet<_>>(),
+            HashSet::from([(1, 2), (2, 1), (2, 3), (3, 2)])
+        );
+        assert_eq!(dg.in_degree(&2), 2);
+    }
+
+    #[test]
+    fn to_undirected() {
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 1), (2, 3)]);
+        dg.add_node(4);
+        let g = dg.to_undirected();
+        assert_eq!(g.nodes::<HashSet<_>>(), HashSet::from([1, 2, 3, 4]));
+        assert_eq!(
+            g.edges::<HashSet<_>>(),
+            HashSet::from([(1, 2), (2, 1), (2, 3), (3, 2)])
+        );
+        assert_eq!(*g.adj(&2).unwrap(), HashSet::from([1, 3]));
+    }
+
+    #[test]
+    fn remove_edge() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert!(g.remove_edge(&2, &1));
+        assert!(!g.remove_edge(&1, &2));
+        assert_eq!(g.nodes::<HashSet<_>>(), HashSet::from([1, 2, 3]));
+        assert_eq!(*g.adj(&1).unwrap(), HashSet::new());
+        assert_eq!(*g.adj(&2).unwrap(), HashSet::from([3]));
+    }
+
+    #[test]
+    fn remove_directed_edge() {
+        let mut g: DiGraph<i8> = DiGraph::new();
+        g.add_edges_from(vec![(1, 2), (2, 1)]);
+        assert!(g.remove_edge(&1, &2));
+        assert_eq!(*g.adj(&1).unwrap(), HashSet::new());
+        assert_eq!(*g.adj(&2).unwrap(), HashSet::from([1]));
+        assert_eq!(g.in_degree(&2), 0);
+        assert_eq!(g.in_degree(&1), 1);
+    }
+
+    #[test]
+    fn remove_nodes_from() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+        g.remove_nodes_from(vec![2, 4, 5]);
+        assert_eq!(g.nodes::<HashSet<_>>(), HashSet::from([1, 3]));
+        assert_eq!(g.edges::<HashSet<_>>(), HashSet::new());
+    }
+
+    #[test]
+    fn remove_directed_nodes_from() {
+        let mut g: DiGraph<i8> = DiGraph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1), (1, 3)]);
+        g.remove_nodes_from(vec![2, 5]);
+        assert_eq!(g.nodes::<HashSet<_>>(), HashSet::from([1, 3]));
+        assert_eq!(g.edges::<HashSet<_>>(), HashSet::from([(3, 1), (1, 3)]));
+        assert_eq!(g.in_degree_map(), HashMap::from([(1, 1), (3, 1)]));
+        assert_eq!(*g.pred(&3).unwrap(), HashSet::from([1]));
+    }
+
+    #[test]
+    fn remove_edges_from() {
+        let mut g: DiGraph<i8> = DiGraph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1)]);
+        g.remove_edges_from(vec![(1, 2), (3, 1), (1, 3)]);
+        assert_eq!(g.edges::<HashSet<_>>(), HashSet::from([(2, 3)]));
+        assert_eq!(g.in_degree_map(), HashMap::from([(1, 0), (2, 0), (3, 1)]));
+    }
+
+    #[test]
+    fn isolates() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6)]);
+        g.add_node(7);
+        assert_eq!(g.isolates::<Vec<_>>(), vec![7]);
+        assert_eq!(g.number_of_isolates(), 1);
+
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6), (8, 8)]);
+        dg.add_node(7);
+        assert_eq!(dg.isolates::<HashSet<_>>(), HashSet::from([7]));
+        assert_eq!(dg.number_of_isolates(), 1);
+    }
+
+    #[test]
+    fn has_edges() {
+        let mut g: DiGraph<i8> = DiGraph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        let actual = g.has_edges(vec![(1, 2), (2, 1), (2, 3), (3, 4), (1, 2)]);
+        assert_eq!(actual, vec![true, false, true, false, true]);
+        assert!(g.has_edges(vec![]).is_empty());
+    }
+
+    #[test]
+    fn neighbors_excluding() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (1, 4)]);
+        let excluded = HashSet::from([3, 5]);
+        let actual = g.neighbors_excluding(&1, &excluded).collect::<HashSet<_>>();
+        assert_eq!(actual, HashSet::from([&2, &4]));
+        assert_eq!(g.neighbors_excluding(&6, &excluded).count(), 0);
+    }
+
     #[test]
     fn no_adj() {
         let g: Graph<i8> = Graph::new();
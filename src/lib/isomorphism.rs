@@ -0,0 +1,470 @@
+//! Graph isomorphism.
+use crate::graph::{Graph, GraphType, Undirected};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A graph relabeled with consecutive indices, with out- and in-neighbors of every node.
+struct Indexed {
+    succ: Vec<HashSet<usize>>,
+    pred: Vec<HashSet<usize>>,
+}
+
+impl Indexed {
+    fn new<T, G>(g: &Graph<T, G>) -> Self
+    where
+        T: Clone + Hash + Eq + Debug,
+        G: GraphType,
+    {
+        let index: HashMap<&T, usize> = g.iter().enumerate().map(|(i, n)| (n, i)).collect();
+        let mut succ: Vec<HashSet<usize>> = vec![HashSet::new(); index.len()];
+        let mut pred: Vec<HashSet<usize>> = vec![HashSet::new(); index.len()];
+
+        for (node, &i) in index.iter() {
+            for neighbor in g.adj(node).unwrap() {
+                succ[i].insert(index[neighbor]);
+                pred[index[neighbor]].insert(i);
+            }
+        }
+        Indexed { succ, pred }
+    }
+
+    fn degree(&self, u: usize) -> (usize, usize) {
+        (self.succ[u].len(), self.pred[u].len())
+    }
+
+    fn degree_sequence(&self) -> Vec<(usize, usize)> {
+        let mut degrees: Vec<(usize, usize)> =
+            (0..self.succ.len()).map(|u| self.degree(u)).collect();
+        degrees.sort();
+        degrees
+    }
+
+    /// Nodes ordered so that each one is adjacent to as many earlier nodes as possible.
+    fn matching_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = Vec::new();
+        let mut placed: Vec<bool> = vec![false; self.succ.len()];
+        let mut connections: Vec<usize> = vec![0; self.succ.len()];
+
+        while order.len() < self.succ.len() {
+            let next = (0..self.succ.len())
+                .filter(|u| !placed[*u])
+                .max_by_key(|u| (connections[*u], self.succ[*u].len() + self.pred[*u].len()))
+                .unwrap();
+            placed[next] = true;
+            order.push(next);
+            for v in self.succ[next].iter().chain(self.pred[next].iter()) {
+                connections[*v] += 1;
+            }
+        }
+        order
+    }
+}
+
+/// Backtracking state for matching nodes of `a` with nodes of `b`.
+struct Matcher<'a> {
+    a: &'a Indexed,
+    b: &'a Indexed,
+    order: Vec<usize>,
+    mapping: Vec<Option<usize>>,
+    inverse: Vec<Option<usize>>,
+}
+
+impl Matcher<'_> {
+    fn feasible(&self, u: usize, v: usize) -> bool {
+        if self.a.degree(u) != self.b.degree(v)
+            || self.a.succ[u].contains(&u) != self.b.succ[v].contains(&v)
+        {
+            return false;
+        }
+        let forward = self.a.succ[u].iter().chain(self.a.pred[u].iter()).all(|x| {
+            self.mapping[*x].is_none_or(|y| {
+                self.a.succ[u].contains(x) == self.b.succ[v].contains(&y)
+                    && self.a.pred[u].contains(x) == self.b.pred[v].contains(&y)
+            })
+        });
+        let backward = self.b.succ[v].iter().chain(self.b.pred[v].iter()).all(|y| {
+            self.inverse[*y].is_none_or(|x| {
+                self.a.succ[u].contains(&x) == self.b.succ[v].contains(y)
+                    && self.a.pred[u].contains(&x) == self.b.pred[v].contains(y)
+            })
+        });
+        forward && backward
+    }
+
+    fn extend(&mut self, depth: usize) -> bool {
+        if depth == self.order.len() {
+            return true;
+        }
+        let u = self.order[depth];
+        for v in 0..self.b.succ.len() {
+            if self.inverse[v].is_some() || !self.feasible(u, v) {
+                continue;
+            }
+            self.mapping[u] = Some(v);
+            self.inverse[v] = Some(u);
+            if self.extend(depth + 1) {
+                return true;
+            }
+            self.mapping[u] = None;
+            self.inverse[v] = None;
+        }
+        false
+    }
+}
+
+/// Returns `true` if graphs `a` and `b` are isomorphic, i.e. are equal up to relabeling of nodes.
+///
+/// Uses backtracking over node mappings, which is exponential in the worst case.
+pub fn is_isomorphic<T, U, G>(a: &Graph<T, G>, b: &Graph<U, G>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+    U: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    if a.node_count() != b.node_count() || a.edge_count() != b.edge_count() {
+        return false;
+    }
+    let ia = Indexed::new(a);
+    let ib = Indexed::new(b);
+    if ia.degree_sequence() != ib.degree_sequence() {
+        return false;
+    }
+
+    let mut matcher = Matcher {
+        a: &ia,
+        b: &ib,
+        order: ia.matching_order(),
+        mapping: vec![None; ia.succ.len()],
+        inverse: vec![None; ib.succ.len()],
+    };
+    matcher.extend(0)
+}
+
+/// Returns `true` if graphs `a` and `b` have the same structure, ignoring node labels.
+///
+/// A convenience wrapper around [`is_isomorphic`] meant for use in assertions.
+pub fn structurally_equal<T, U, G>(a: &Graph<T, G>, b: &Graph<U, G>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+    U: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    is_isomorphic(a, b)
+}
+
+/// The maximum number of partial mappings explored by [`graph_edit_distance`].
+const EDIT_SEARCH_BUDGET: usize = 100_000;
+
+/// Branch and bound state for mapping nodes of `a` to nodes of `b`, or deleting them.
+struct EditSearch<'a> {
+    a: &'a Indexed,
+    b: &'a Indexed,
+    directed: bool,
+    order: Vec<usize>,
+    mapping: Vec<Option<usize>>,
+    used: Vec<bool>,
+    budget: usize,
+    best: usize,
+}
+
+impl EditSearch<'_> {
+    /// Returns 1 if the arc `u -> x` of `a` and its image `v -> y` in `b` differ.
+    fn arc_cost(&self, u: usize, x: usize, v: Option<usize>, y: Option<usize>) -> usize {
+        let in_b = match (v, y) {
+            (Some(v), Some(y)) => self.b.succ[v].contains(&y),
+            _ => false,
+        };
+        usize::from(self.a.succ[u].contains(&x) != in_b)
+    }
+
+    /// The cost of mapping `u` to `v`, given the nodes placed before it.
+    fn step_cost(&self, depth: usize, u: usize, v: Option<usize>) -> usize {
+        let mut cost = usize::from(v.is_none()) + self.arc_cost(u, u, v, v);
+        for &x in &self.order[..depth] {
+            let y = self.mapping[x];
+            cost += self.arc_cost(u, x, v, y);
+            if self.directed {
+                cost += self.arc_cost(x, u, y, v);
+            }
+        }
+        cost
+    }
+
+    /// The cost of inserting nodes of `b` left unmapped, together with their arcs.
+    fn insertion_cost(&self) -> usize {
+        let nodes = self.used.iter().filter(|u| !**u).count();
+        let arcs = (0..self.used.len())
+            .flat_map(|v| self.b.succ[v].iter().map(move |y| (v, *y)))
+            .filter(|(v, y)| (self.directed || v <= y) && !(self.used[*v] && self.used[*y]))
+            .count();
+        nodes + arcs
+    }
+
+    fn extend(&mut self, depth: usize, cost: usize) {
+        if self.budget == 0 {
+            return;
+        }
+        self.budget -= 1;
+        let free = self.used.iter().filter(|u| !**u).count();
+        if cost + (self.order.len() - depth).abs_diff(free) >= self.best {
+            return;
+        }
+        if depth == self.order.len() {
+            self.best = self.best.min(cost + self.insertion_cost());
+            return;
+        }
+        let u = self.order[depth];
+        let mut candidates: Vec<(usize, Option<usize>)> = (0..self.used.len())
+            .filter(|v| !self.used[*v])
+            .map(Some)
+            .chain([None])
+            .map(|v| (self.step_cost(depth, u, v), v))
+            .collect();
+        candidates.sort();
+
+        for (step, v) in candidates {
+            self.mapping[u] = v;
+            if let Some(v) = v {
+                self.used[v] = true;
+            }
+            self.extend(depth + 1, cost + step);
+            if let Some(v) = v {
+                self.used[v] = false;
+            }
+            self.mapping[u] = None;
+        }
+    }
+}
+
+/// Returns the number of node and edge insertions and deletions needed to turn `a` into a graph
+/// isomorphic to `b`.
+///
+/// Searches node mappings with branch and bound, trying the cheapest extensions first.
+/// The result is exact for small graphs (up to about ten nodes). For larger ones the search
+/// gives up after a fixed number of steps, and the result is only an upper bound.
+pub fn graph_edit_distance<T, U, G>(a: &Graph<T, G>, b: &Graph<U, G>) -> usize
+where
+    T: Clone + Hash + Eq + Debug,
+    U: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let ia = Indexed::new(a);
+    let ib = Indexed::new(b);
+    let mut search = EditSearch {
+        a: &ia,
+        b: &ib,
+        directed: G::is_directed(),
+        order: ia.matching_order(),
+        mapping: vec![None; ia.succ.len()],
+        used: vec![false; ib.succ.len()],
+        budget: EDIT_SEARCH_BUDGET,
+        best: a.node_count() + a.edge_count() + b.node_count() + b.edge_count(),
+    };
+    search.extend(0, 0);
+    search.best
+}
+
+/// A 64-bit FNV-1a hasher. Unlike `DefaultHasher`, its output is fixed across
+/// platforms and Rust releases, so labels can be stored and compared later.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    /// Writes a string prefixed with its length, so that concatenations are unambiguous.
+    fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+
+    /// Returns the hash as a fixed-width hexadecimal label.
+    fn label(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+/// Computes the Weisfeiler–Lehman hash of an undirected graph.
+///
+/// Every node starts labeled with its degree. In each of `iterations` rounds a
+/// label is replaced by a hash of itself and the sorted labels of neighbors.
+/// The result hashes counts of all labels seen. Isomorphic graphs always have
+/// equal hashes; non-isomorphic graphs usually, but not always, differ.
+///
+/// Labels are hashed with FNV-1a over a fixed byte encoding, so the result is
+/// stable across platforms and releases and can be used as a cache key.
+pub fn weisfeiler_lehman_hash<T>(g: &Graph<T, Undirected>, iterations: usize) -> String
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut labels: HashMap<&T, String> = g.iter().map(|u| (u, g.degree(u).to_string())).collect();
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    let mut record = |labels: &HashMap<&T, String>| {
+        let mut histogram: HashMap<&String, usize> = HashMap::new();
+        labels
+            .values()
+            .for_each(|l| *histogram.entry(l).or_default() += 1);
+        let mut histogram: Vec<(String, usize)> =
+            histogram.into_iter().map(|(l, c)| (l.clone(), c)).collect();
+        histogram.sort();
+        counts.extend(histogram);
+    };
+    record(&labels);
+
+    for _ in 0..iterations {
+        labels = labels
+            .iter()
+            .map(|(u, label)| {
+                let mut neighborhood: Vec<&String> =
+                    g.adj(u).unwrap().iter().map(|v| &labels[v]).collect();
+                neighborhood.sort();
+                let mut hasher = Fnv1a::new();
+                hasher.write_str(label);
+                neighborhood.into_iter().for_each(|l| hasher.write_str(l));
+                (*u, hasher.label())
+            })
+            .collect();
+        record(&labels);
+    }
+    let mut hasher = Fnv1a::new();
+    for (label, count) in counts {
+        hasher.write_str(&label);
+        hasher.write(&(count as u64).to_le_bytes());
+    }
+    hasher.label()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DiGraph;
+
+    #[test]
+    fn relabeled_graph_is_isomorphic() {
+        let mut a: Graph<i8> = Graph::new();
+        a.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1), (1, 3)]);
+        let mut b: Graph<char> = Graph::new();
+        b.add_edges_from(vec![
+            ('a', 'b'),
+            ('b', 'c'),
+            ('c', 'd'),
+            ('d', 'a'),
+            ('b', 'd'),
+        ]);
+        assert!(is_isomorphic(&a, &b));
+
+        let mut c: Graph<i8> = Graph::new();
+        c.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1), (2, 4)]);
+        assert_ne!(a, c);
+        assert!(structurally_equal(&a, &c));
+    }
+
+    #[test]
+    fn same_degrees_not_isomorphic() {
+        let mut a: Graph<i8> = Graph::new();
+        a.add_edges_from(vec![(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4)]);
+        let mut b: Graph<i8> = Graph::new();
+        b.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 1)]);
+        assert!(!structurally_equal(&a, &b));
+    }
+
+    #[test]
+    fn relabeled_graphs_share_wl_hash() {
+        let mut a: Graph<i8> = Graph::new();
+        a.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1), (1, 3), (4, 5)]);
+        let mut b: Graph<char> = Graph::new();
+        b.add_edges_from(vec![
+            ('d', 'c'),
+            ('c', 'b'),
+            ('b', 'a'),
+            ('a', 'd'),
+            ('d', 'b'),
+            ('a', 'e'),
+        ]);
+        assert_eq!(weisfeiler_lehman_hash(&a, 3), weisfeiler_lehman_hash(&b, 3));
+    }
+
+    #[test]
+    fn triangle_and_path_wl_hash_differ() {
+        let mut triangle: Graph<i8> = Graph::new();
+        triangle.add_edges_from(vec![(1, 2), (2, 3), (3, 1)]);
+        let mut path: Graph<i8> = Graph::new();
+        path.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert_ne!(
+            weisfeiler_lehman_hash(&triangle, 2),
+            weisfeiler_lehman_hash(&path, 2)
+        );
+    }
+
+    #[test]
+    fn wl_hash_is_pinned() {
+        let mut hasher = Fnv1a::new();
+        assert_eq!(hasher.label(), "cbf29ce484222325");
+        hasher.write(b"a");
+        assert_eq!(hasher.label(), "af63dc4c8601ec8c");
+
+        let mut path: Graph<i8> = Graph::new();
+        path.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert_eq!(weisfeiler_lehman_hash(&path, 2), "dd3b3241968367f5");
+    }
+
+    #[test]
+    fn directed_isomorphism() {
+        let mut a: DiGraph<i8> = DiGraph::new();
+        a.add_edges_from(vec![(1, 2), (2, 3)]);
+        let mut b: DiGraph<i8> = DiGraph::new();
+        b.add_edges_from(vec![(3, 2), (2, 1)]);
+        let mut c: DiGraph<i8> = DiGraph::new();
+        c.add_edges_from(vec![(1, 2), (3, 2)]);
+        assert!(structurally_equal(&a, &b));
+        assert!(!structurally_equal(&a, &c));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn edit_distance_extra_edge() {
+        let mut a: Graph<i8> = Graph::new();
+        a.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+        let mut b = a.clone();
+        assert_eq!(graph_edit_distance(&a, &b), 0);
+
+        b.add_edge(1, 3);
+        assert_eq!(graph_edit_distance(&a, &b), 1);
+        assert_eq!(graph_edit_distance(&b, &a), 1);
+
+        b.add_edge(3, 5);
+        assert_eq!(graph_edit_distance(&a, &b), 3);
+        assert_eq!(graph_edit_distance(&a, &Graph::<char>::new()), 8);
+    }
+
+    #[test]
+    fn large_edit_distance_is_bounded() {
+        let cycle = |n: u16, offset: u16| (0..n).map(move |i| (offset + i, offset + (i + 1) % n));
+        let mut a: Graph<u16> = Graph::new();
+        a.add_edges_from(cycle(30, 0).collect());
+        let mut b: Graph<u16> = Graph::new();
+        b.add_edges_from(cycle(15, 0).chain(cycle(15, 15)).collect());
+
+        assert_eq!(graph_edit_distance(&a, &a.clone()), 0);
+        let distance = graph_edit_distance(&a, &b);
+        assert!((4..=60).contains(&distance));
+    }
+
+    #[test]
+    fn directed_edit_distance() {
+        let mut a: DiGraph<i8> = DiGraph::new();
+        a.add_edges_from(vec![(1, 2), (2, 3)]);
+        let mut b: DiGraph<i8> = DiGraph::new();
+        b.add_edges_from(vec![(1, 2), (3, 2)]);
+        assert_eq!(graph_edit_distance(&a, &b), 2);
+    }
+}
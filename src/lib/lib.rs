@@ -4,3 +4,51 @@ pub use graph::*;
 pub use sort::*;
 pub mod search;
 pub use search::*;
+pub mod centrality;
+pub use centrality::*;
+pub mod components;
+pub use components::*;
+pub mod community;
+pub use community::*;
+pub mod weighted;
+pub use weighted::*;
+pub mod weighted_search;
+pub use weighted_search::*;
+pub mod approximation;
+pub use approximation::*;
+pub mod connectivity;
+mod random;
+pub use connectivity::*;
+pub mod cycles;
+pub use cycles::*;
+pub mod isomorphism;
+pub use isomorphism::*;
+pub mod distance_measures;
+pub use distance_measures::*;
+pub mod assortativity;
+pub use assortativity::*;
+pub mod clique;
+pub use clique::*;
+pub mod cores;
+pub use cores::*;
+pub mod planarity;
+pub use planarity::*;
+pub mod walks;
+pub use walks::*;
+pub mod multigraph;
+pub use multigraph::*;
+pub mod readwrite;
+pub use readwrite::*;
+pub mod views;
+pub use views::*;
+pub mod operators;
+pub mod regular;
+pub use regular::*;
+pub mod branchings;
+pub use branchings::*;
+pub mod bridges;
+pub use bridges::*;
+pub mod matching;
+pub use matching::*;
+pub mod euler;
+pub use euler::*;
@@ -0,0 +1,82 @@
+//! Matchings in a graph.
+use crate::graph::Undirected;
+use crate::weighted::{Weight, WeightedGraph};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns a matching of large total weight, i.e. a set of edges without common ends.
+///
+/// This is a greedy approximation, not an exact algorithm: edges are taken heaviest first
+/// whenever both ends are still free. The weight of the result is at least half of the
+/// maximum. Edges with non-positive weights and self-loops are never taken.
+pub fn max_weight_matching<T, W>(g: &WeightedGraph<T, Undirected, W>) -> Vec<(T, T, W)>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    let mut seen: HashSet<&T> = HashSet::new();
+    let mut edges: Vec<(&T, &T, W)> = Vec::new();
+    for u in g.iter() {
+        seen.insert(u);
+        for v in g.adj(u).unwrap() {
+            let w = g.weight(u, v).unwrap();
+            if !seen.contains(v) && w > W::zero() {
+                edges.push((u, v, w));
+            }
+        }
+    }
+    edges.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(Ordering::Equal));
+
+    let mut matched: HashSet<&T> = HashSet::new();
+    let mut matching: Vec<(T, T, W)> = Vec::new();
+    for (u, v, w) in edges {
+        if !matched.contains(u) && !matched.contains(v) {
+            matched.insert(u);
+            matched.insert(v);
+            matching.push((u.clone(), v.clone(), w));
+        }
+    }
+    matching
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total(matching: &[(i8, i8, u32)]) -> u32 {
+        matching.iter().map(|(_, _, w)| w).sum()
+    }
+
+    fn is_matching(matching: &[(i8, i8, u32)]) -> bool {
+        let ends: HashSet<i8> = matching.iter().flat_map(|(u, v, _)| [*u, *v]).collect();
+        ends.len() == 2 * matching.len()
+    }
+
+    #[test]
+    fn greedy_finds_optimum() {
+        let mut g: WeightedGraph<i8, Undirected, u32> = WeightedGraph::new();
+        g.add_weighted_edges_from([
+            (1, 2, 6),
+            (2, 3, 4),
+            (3, 4, 5),
+            (4, 1, 1),
+            (1, 3, 2),
+            (5, 5, 9),
+        ]);
+        let actual = max_weight_matching(&g);
+        assert!(is_matching(&actual));
+        assert_eq!(total(&actual), 11);
+    }
+
+    #[test]
+    fn greedy_is_half_approximation() {
+        let mut g: WeightedGraph<i8, Undirected, u32> = WeightedGraph::new();
+        g.add_weighted_edges_from([(1, 2, 2), (2, 3, 3), (3, 4, 2), (4, 5, 0)]);
+        let actual = max_weight_matching(&g);
+        assert!(is_matching(&actual));
+        assert_eq!(total(&actual), 3);
+        assert!(2 * total(&actual) >= 4);
+    }
+}
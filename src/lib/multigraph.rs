@@ -0,0 +1,195 @@
+//! Definition of multigraphs.
+use crate::graph::Undirected;
+use crate::weighted::{Weight, WeightedGraph};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A unique identifier of an edge in a multigraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeId(usize);
+
+/// An undirected weighted graph which allows many edges between the same pair of nodes.
+#[derive(Debug, Clone)]
+pub struct MultiGraph<T, W = f64>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    adj: HashMap<T, Vec<(T, W, EdgeId)>>,
+    ends: HashMap<EdgeId, (T, T)>,
+    next_id: usize,
+}
+
+impl<T, W> Default for MultiGraph<T, W>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, W> MultiGraph<T, W>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    /// Create an empty multigraph.
+    pub fn new() -> Self {
+        MultiGraph {
+            adj: HashMap::new(),
+            ends: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Get all nodes from a graph.
+    pub fn nodes<B: FromIterator<T>>(&self) -> B {
+        self.adj.keys().cloned().collect()
+    }
+
+    /// Returns the number of nodes in a graph.
+    pub fn node_count(&self) -> usize {
+        self.adj.len()
+    }
+
+    /// Returns the number of edges in a graph.
+    pub fn edge_count(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Get edges adjacent to a node as `(neighbor, weight, id)` triples.
+    pub fn adj(&self, u: &T) -> Option<&Vec<(T, W, EdgeId)>> {
+        self.adj.get(u)
+    }
+
+    /// Get the ends of an edge.
+    pub fn edge_ends(&self, id: EdgeId) -> Option<&(T, T)> {
+        self.ends.get(&id)
+    }
+
+    /// Add a node. Do nothing if it already exists.
+    pub fn add_node(&mut self, u: T) {
+        self.adj.entry(u).or_default();
+    }
+
+    /// Adds an edge (u<->v), even if the nodes are already connected, and returns its id.
+    pub fn add_edge(&mut self, u: T, v: T, weight: W) -> EdgeId {
+        let id = EdgeId(self.next_id);
+        self.next_id += 1;
+
+        self.adj
+            .entry(u.clone())
+            .or_default()
+            .push((v.clone(), weight, id));
+        if u != v {
+            self.adj
+                .entry(v.clone())
+                .or_default()
+                .push((u.clone(), weight, id));
+        }
+        self.ends.insert(id, (u, v));
+        id
+    }
+
+    /// Removes an edge with a given id. Returns `false` if there was no such edge.
+    pub fn remove_edge_by_id(&mut self, id: EdgeId) -> bool {
+        match self.ends.remove(&id) {
+            Some((u, v)) => {
+                for node in [u, v] {
+                    if let Some(edges) = self.adj.get_mut(&node) {
+                        edges.retain(|(_, _, e)| *e != id);
+                    }
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of edges between `u` and `v`.
+    pub fn number_of_edges(&self, u: &T, v: &T) -> usize {
+        self.adj
+            .get(u)
+            .map_or(0, |edges| edges.iter().filter(|(w, _, _)| w == v).count())
+    }
+
+    /// Converts to a weighted graph, merging parallel edges into a single edge
+    /// weighing the sum of their weights. With unit weights, the weight is the number of edges.
+    pub fn to_weighted(&self) -> WeightedGraph<T, Undirected, W> {
+        let mut g: WeightedGraph<T, Undirected, W> = WeightedGraph::new();
+        for u in self.adj.keys() {
+            g.add_node(u.clone());
+        }
+        for (u, edges) in self.adj.iter() {
+            for (v, weight, id) in edges {
+                if self.ends[id].0 == *u {
+                    let total = g.weight(u, v).map_or(*weight, |w| w + *weight);
+                    g.add_edge(u.clone(), v.clone(), total);
+                }
+            }
+        }
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_edges() {
+        let mut g: MultiGraph<i8> = MultiGraph::new();
+        let a = g.add_edge(1, 2, 0.5);
+        let b = g.add_edge(2, 1, 1.5);
+        g.add_edge(2, 3, 1.0);
+        assert_ne!(a, b);
+        assert_eq!(g.number_of_edges(&1, &2), 2);
+        assert_eq!(g.number_of_edges(&2, &1), 2);
+        assert_eq!(g.edge_count(), 3);
+        assert_eq!(g.edge_ends(b), Some(&(2, 1)));
+    }
+
+    #[test]
+    fn remove_parallel_edge_by_id() {
+        let mut g: MultiGraph<i8> = MultiGraph::new();
+        let a = g.add_edge(1, 2, 0.5);
+        let b = g.add_edge(1, 2, 1.5);
+        assert!(g.remove_edge_by_id(a));
+        assert!(!g.remove_edge_by_id(a));
+        assert_eq!(g.number_of_edges(&1, &2), 1);
+        assert_eq!(*g.adj(&1).unwrap(), vec![(2, 1.5, b)]);
+        assert_eq!(*g.adj(&2).unwrap(), vec![(1, 1.5, b)]);
+        assert_eq!(g.node_count(), 2);
+    }
+
+    #[test]
+    fn self_loop_is_stored_once() {
+        let mut g: MultiGraph<i8, u32> = MultiGraph::new();
+        let a = g.add_edge(1, 1, 2);
+        assert_eq!(g.number_of_edges(&1, &1), 1);
+        assert!(g.remove_edge_by_id(a));
+        assert_eq!(g.number_of_edges(&1, &1), 0);
+    }
+
+    #[test]
+    fn merge_parallel_edges_into_weights() {
+        let mut g: MultiGraph<i8> = MultiGraph::new();
+        g.add_edge(1, 2, 1.0);
+        g.add_edge(2, 1, 1.0);
+        g.add_edge(2, 3, 0.5);
+        g.add_edge(3, 3, 0.25);
+        g.add_edge(3, 3, 0.25);
+        g.add_node(4);
+
+        let w = g.to_weighted();
+        assert_eq!(w.weight(&1, &2), Some(2.0));
+        assert_eq!(w.weight(&2, &1), Some(2.0));
+        assert_eq!(w.weight(&3, &2), Some(0.5));
+        assert_eq!(w.weight(&3, &3), Some(0.5));
+        assert_eq!(w.graph().edge_count(), 3);
+        assert_eq!(w.graph().node_count(), 4);
+    }
+}
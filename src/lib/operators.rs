@@ -0,0 +1,153 @@
+//! Operations combining two graphs into one.
+use crate::graph::{Graph, Undirected};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+impl<T> Graph<T, Undirected>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    /// Returns the join of two graphs: their disjoint union with every node of
+    /// `self` connected to every node of `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graphs share a node label. Relabel one of them first.
+    pub fn join(&self, other: &Graph<T, Undirected>) -> Graph<T, Undirected> {
+        assert!(
+            self.iter().all(|u| other.adj(u).is_none()),
+            "node labels of joined graphs must be disjoint"
+        );
+        let mut g = self.clone();
+        for u in other.iter() {
+            g.add_node(u.clone());
+        }
+        for (u, v) in other.edges::<Vec<_>>() {
+            g.add_edge(u, v);
+        }
+        for u in self.iter() {
+            for v in other.iter() {
+                g.add_edge(u.clone(), v.clone());
+            }
+        }
+        g
+    }
+
+    /// Returns the Cartesian product of two graphs.
+    ///
+    /// `(a, b)` and `(c, d)` are adjacent iff either `a == c` and `b` is adjacent
+    /// to `d` in `other`, or `b == d` and `a` is adjacent to `c` in `self`.
+    pub fn cartesian_product<U>(&self, other: &Graph<U, Undirected>) -> Graph<(T, U), Undirected>
+    where
+        U: Clone + Hash + Eq + Debug,
+    {
+        let mut g: Graph<(T, U)> = Graph::new();
+        for a in self.iter() {
+            for b in other.iter() {
+                g.add_node((a.clone(), b.clone()));
+                for d in other.adj(b).into_iter().flatten() {
+                    g.add_edge((a.clone(), b.clone()), (a.clone(), d.clone()));
+                }
+                for c in self.adj(a).into_iter().flatten() {
+                    g.add_edge((a.clone(), b.clone()), (c.clone(), b.clone()));
+                }
+            }
+        }
+        g
+    }
+
+    /// Returns the tensor (categorical) product of two graphs.
+    ///
+    /// `(a, b)` and `(c, d)` are adjacent iff `a` is adjacent to `c` in `self`
+    /// and `b` is adjacent to `d` in `other`.
+    pub fn tensor_product<U>(&self, other: &Graph<U, Undirected>) -> Graph<(T, U), Undirected>
+    where
+        U: Clone + Hash + Eq + Debug,
+    {
+        let mut g: Graph<(T, U)> = Graph::new();
+        for a in self.iter() {
+            for b in other.iter() {
+                g.add_node((a.clone(), b.clone()));
+                for c in self.adj(a).into_iter().flatten() {
+                    for d in other.adj(b).into_iter().flatten() {
+                        g.add_edge((a.clone(), b.clone()), (c.clone(), d.clone()));
+                    }
+                }
+            }
+        }
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join() {
+        let mut g1: Graph<i8> = Graph::new();
+        g1.add_edges_from(vec![(1, 2), (2, 3)]);
+        let mut g2: Graph<i8> = Graph::new();
+        g2.add_edges_from(vec![(4, 5), (5, 6), (6, 4)]);
+        g2.add_node(7);
+
+        let g = g1.join(&g2);
+        assert_eq!(g.node_count(), 7);
+        assert_eq!(g.edge_count(), 2 + 3 + 3 * 4);
+        assert!(g.has_edge(&1, &7));
+        assert!(!g.has_edge(&1, &3));
+    }
+
+    #[test]
+    fn join_single_nodes() {
+        let mut g1: Graph<i8> = Graph::new();
+        g1.add_node(1);
+        let mut g2: Graph<i8> = Graph::new();
+        g2.add_node(2);
+
+        let g = g1.join(&g2);
+        assert_eq!(g.edge_count(), 1);
+        assert!(g.has_edge(&2, &1));
+    }
+
+    #[test]
+    fn cartesian_product() {
+        let mut p2: Graph<i8> = Graph::new();
+        p2.add_edge(1, 2);
+        let mut p3: Graph<char> = Graph::new();
+        p3.add_edges_from(vec![('a', 'b'), ('b', 'c')]);
+
+        let g = p2.cartesian_product(&p3);
+        assert_eq!(g.node_count(), 6);
+        assert_eq!(g.edge_count(), 7);
+        assert!(g.has_edge(&(1, 'a'), &(1, 'b')));
+        assert!(g.has_edge(&(1, 'b'), &(2, 'b')));
+        assert!(!g.has_edge(&(1, 'a'), &(2, 'b')));
+    }
+
+    #[test]
+    fn tensor_product() {
+        let mut p2: Graph<i8> = Graph::new();
+        p2.add_edge(1, 2);
+        let mut p3: Graph<char> = Graph::new();
+        p3.add_edges_from(vec![('a', 'b'), ('b', 'c')]);
+
+        let g = p2.tensor_product(&p3);
+        assert_eq!(g.node_count(), 6);
+        assert_eq!(g.edge_count(), 4);
+        assert!(g.has_edge(&(1, 'a'), &(2, 'b')));
+        assert!(g.has_edge(&(2, 'a'), &(1, 'b')));
+        assert!(g.has_edge(&(1, 'b'), &(2, 'c')));
+        assert!(!g.has_edge(&(1, 'a'), &(1, 'b')));
+        assert!(!g.has_edge(&(1, 'b'), &(2, 'b')));
+        assert!(!g.has_edge(&(1, 'a'), &(2, 'c')));
+    }
+
+    #[test]
+    #[should_panic]
+    fn join_overlapping_labels() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edge(1, 2);
+        g.join(&g.clone());
+    }
+}
@@ -0,0 +1,110 @@
+//! Planarity of an undirected graph.
+use crate::components::connected_components;
+use crate::graph::{Graph, Undirected};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns `false` if an undirected graph is certainly not planar.
+///
+/// This is a heuristic suitable for small graphs rather than a full planarity
+/// test. Self-loops are dropped, nodes of degree at most one are removed and
+/// nodes of degree two are smoothed out, which preserves planarity and turns
+/// subdivisions of K5 and K3,3 back into K5 and K3,3. Every remaining component
+/// is then checked against Euler's bounds: `m <= 3n - 6`, or `m <= 2n - 4` if
+/// it has no triangles. K5, K3,3, their subdivisions, trees and cycles are
+/// classified correctly, but some non-planar graphs satisfying the bounds
+/// (e.g. the Petersen graph) are reported as planar.
+pub fn is_planar<T>(g: &Graph<T, Undirected>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut h = g.clone();
+    for u in g.iter() {
+        h.remove_edge(u, u);
+    }
+    let mut queue: Vec<T> = h.nodes();
+
+    while let Some(u) = queue.pop() {
+        let neighbors: Vec<T> = match h.adj(&u) {
+            Some(neighbors) if neighbors.len() <= 2 => neighbors.iter().cloned().collect(),
+            _ => continue,
+        };
+        h.remove_node(&u);
+        if let [v, w] = &neighbors[..] {
+            h.add_edge(v.clone(), w.clone());
+        }
+        queue.extend(neighbors);
+    }
+
+    connected_components(&h).into_iter().all(|component| {
+        let c = h.subgraph(&component);
+        let (n, m) = (c.node_count(), c.edge_count());
+        if n < 3 {
+            return true;
+        }
+        let has_triangle = c.iter().any(|u| {
+            let neighbors = c.adj(u).unwrap();
+            neighbors
+                .iter()
+                .any(|v| c.adj(v).unwrap().iter().any(|w| neighbors.contains(w)))
+        });
+        m <= 3 * n - 6 && (has_triangle || m <= 2 * n - 4)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete_graph(n: i8) -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        for u in 0..n {
+            for v in (u + 1)..n {
+                g.add_edge(u, v);
+            }
+        }
+        g
+    }
+
+    fn complete_bipartite_graph(n: i8, m: i8) -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        for u in 0..n {
+            for v in n..(n + m) {
+                g.add_edge(u, v);
+            }
+        }
+        g
+    }
+
+    #[test]
+    fn kuratowski_graphs_are_not_planar() {
+        assert!(!is_planar(&complete_graph(5)));
+        assert!(!is_planar(&complete_bipartite_graph(3, 3)));
+    }
+
+    #[test]
+    fn subdivided_k33_is_not_planar() {
+        let mut g = complete_bipartite_graph(3, 3);
+        g.remove_edge(&0, &3);
+        g.add_edges_from(vec![(0, 10), (10, 11), (11, 3), (11, 12), (12, 13)]);
+        assert!(!is_planar(&g));
+    }
+
+    #[test]
+    fn trees_and_cycles_are_planar() {
+        let mut tree: Graph<i8> = Graph::new();
+        tree.add_edges_from(vec![(1, 2), (1, 3), (3, 4), (3, 5), (5, 6)]);
+        assert!(is_planar(&tree));
+
+        let mut cycle: Graph<i8> = Graph::new();
+        cycle.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+        assert!(is_planar(&cycle));
+    }
+
+    #[test]
+    fn small_graphs_are_planar() {
+        assert!(is_planar(&complete_graph(4)));
+        assert!(is_planar(&complete_bipartite_graph(2, 5)));
+        assert!(is_planar(&Graph::<i8>::new()));
+    }
+}
@@ -0,0 +1,712 @@
+//! Reading and writing graphs in common formats.
+use crate::graph::{Directed, Graph, GraphType, Undirected};
+use crate::weighted::{Weight, WeightedGraph};
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::io::{Read, Write};
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Failed to parse a graph: {}", self.0)
+    }
+}
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+/// A parsed JSON value. Numbers are kept as their source text.
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn parse(s: &str) -> Result<Json> {
+        let mut chars = s.chars().peekable();
+        let value = parse_value(&mut chars, 0)?;
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            None => Ok(value),
+            Some(c) => Err(ParseError(format!("unexpected character '{}'", c))),
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Result<()> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(ParseError(format!(
+            "expected '{}', found '{}'",
+            expected, c
+        ))),
+        None => Err(ParseError(format!(
+            "expected '{}', found end of input",
+            expected
+        ))),
+    }
+}
+
+/// Parses four hex digits of a `\u` escape.
+fn parse_hex4(chars: &mut Peekable<Chars>) -> Result<u32> {
+    let code: String = chars.by_ref().take(4).collect();
+    u32::from_str_radix(&code, 16)
+        .ok()
+        .filter(|_| code.len() == 4)
+        .ok_or_else(|| ParseError(format!("invalid escape '\\u{}'", code)))
+}
+
+/// Parses a `\u` escape following the `\u`, combining a UTF-16 surrogate pair
+/// written as two escapes into one character.
+fn parse_unicode_escape(chars: &mut Peekable<Chars>) -> Result<char> {
+    let mut code = parse_hex4(chars)?;
+    if (0xd800..0xdc00).contains(&code) {
+        let low = match (chars.next(), chars.next()) {
+            (Some('\\'), Some('u')) => parse_hex4(chars)?,
+            _ => return Err(ParseError(format!("unpaired surrogate '\\u{:x}'", code))),
+        };
+        if !(0xdc00..0xe000).contains(&low) {
+            return Err(ParseError(format!("unpaired surrogate '\\u{:x}'", code)));
+        }
+        code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+    }
+    char::from_u32(code).ok_or_else(|| ParseError(format!("invalid escape '\\u{:x}'", code)))
+}
+
+/// Maximum nesting depth of JSON arrays and objects, bounding recursion.
+const MAX_JSON_DEPTH: usize = 256;
+
+/// Parses a JSON value nested in `depth` arrays or objects.
+fn parse_value(chars: &mut Peekable<Chars>, depth: usize) -> Result<Json> {
+    if depth > MAX_JSON_DEPTH {
+        return Err(ParseError("nesting too deep".to_string()));
+    }
+    skip_whitespace(chars);
+    match chars.peek() {
+        Some('{') => {
+            chars.next();
+            let mut fields: Vec<(String, Json)> = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'}').is_some() {
+                return Ok(Json::Object(fields));
+            }
+            loop {
+                skip_whitespace(chars);
+                let key = match parse_value(chars, depth + 1)? {
+                    Json::String(key) => key,
+                    _ => return Err(ParseError("object keys must be strings".to_string())),
+                };
+                expect(chars, ':')?;
+                fields.push((key, parse_value(chars, depth + 1)?));
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => continue,
+                    Some('}') => return Ok(Json::Object(fields)),
+                    _ => return Err(ParseError("unterminated object".to_string())),
+                }
+            }
+        }
+        Some('[') => {
+            chars.next();
+            let mut items: Vec<Json> = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&']').is_some() {
+                return Ok(Json::Array(items));
+            }
+            loop {
+                items.push(parse_value(chars, depth + 1)?);
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => continue,
+                    Some(']') => return Ok(Json::Array(items)),
+                    _ => return Err(ParseError("unterminated array".to_string())),
+                }
+            }
+        }
+        Some('"') => {
+            chars.next();
+            let mut s = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => return Ok(Json::String(s)),
+                    Some('\\') => match chars.next() {
+                        Some('n') => s.push('\n'),
+                        Some('t') => s.push('\t'),
+                        Some('r') => s.push('\r'),
+                        Some('b') => s.push('\u{8}'),
+                        Some('f') => s.push('\u{c}'),
+                        Some('u') => s.push(parse_unicode_escape(chars)?),
+                        Some(c) => s.push(c),
+                        None => return Err(ParseError("unterminated string".to_string())),
+                    },
+                    Some(c) => s.push(c),
+                    None => return Err(ParseError("unterminated string".to_string())),
+                }
+            }
+        }
+        Some(c) if *c == '-' || c.is_ascii_digit() => {
+            let mut s = String::new();
+            while let Some(c) = chars.next_if(|c| "+-.eE".contains(*c) || c.is_ascii_digit()) {
+                s.push(c);
+            }
+            Ok(Json::Number(s))
+        }
+        Some(_) => {
+            let mut word = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                word.push(c);
+            }
+            match word.as_str() {
+                "true" => Ok(Json::Bool(true)),
+                "false" => Ok(Json::Bool(false)),
+                "null" => Ok(Json::Null),
+                _ => Err(ParseError(format!("unexpected token '{}'", word))),
+            }
+        }
+        None => Err(ParseError("unexpected end of input".to_string())),
+    }
+}
+
+/// A node type that can be written to node-link JSON.
+///
+/// Integers are written as JSON numbers. Other types are written as JSON strings
+/// of their textual form, so a custom node type only needs an empty impl.
+pub trait NodeLinkId: Display {
+    /// Returns `true` if a node is written as a JSON number.
+    fn is_json_number(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_node_link_id {
+    ($($t:ty),*) => {
+        $(
+            impl NodeLinkId for $t {
+                fn is_json_number(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_node_link_id!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl NodeLinkId for String {}
+impl NodeLinkId for &str {}
+impl NodeLinkId for char {}
+impl NodeLinkId for bool {}
+
+/// Formats a node as a JSON number or string, depending on its type.
+fn json_id<T: NodeLinkId>(node: &T) -> String {
+    let s = node.to_string();
+    if node.is_json_number() {
+        return s;
+    }
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Parses a node from a JSON number or string.
+fn parse_id<T: FromStr>(value: &Json) -> Result<T> {
+    let s = match value {
+        Json::Number(s) | Json::String(s) => s,
+        _ => {
+            return Err(ParseError(
+                "node ids must be numbers or strings".to_string(),
+            ))
+        }
+    };
+    s.parse::<T>()
+        .map_err(|_| ParseError(format!("invalid node id '{}'", s)))
+}
+
+/// Nodes and links read from a node-link JSON document.
+type NodeLink<T> = (Vec<T>, Vec<(T, T)>);
+
+fn parse_node_link<T: FromStr>(s: &str, directed: bool) -> Result<NodeLink<T>> {
+    let json = Json::parse(s)?;
+    match json.get("directed") {
+        Some(Json::Bool(d)) if *d == directed => {}
+        Some(Json::Bool(_)) => return Err(ParseError("mismatched directedness".to_string())),
+        _ => return Err(ParseError("missing 'directed' flag".to_string())),
+    }
+
+    let nodes = match json.get("nodes") {
+        Some(Json::Array(nodes)) => nodes
+            .iter()
+            .map(|n| {
+                parse_id(
+                    n.get("id")
+                        .ok_or(ParseError("node without id".to_string()))?,
+                )
+            })
+            .collect::<Result<Vec<T>>>()?,
+        _ => return Err(ParseError("missing 'nodes' array".to_string())),
+    };
+    let links = match json.get("links").or(json.get("edges")) {
+        Some(Json::Array(links)) => links
+            .iter()
+            .map(|l| match (l.get("source"), l.get("target")) {
+                (Some(u), Some(v)) => Ok((parse_id(u)?, parse_id(v)?)),
+                _ => Err(ParseError("link without source or target".to_string())),
+            })
+            .collect::<Result<Vec<(T, T)>>>()?,
+        _ => return Err(ParseError("missing 'links' array".to_string())),
+    };
+    Ok((nodes, links))
+}
+
+impl<T, G> Graph<T, G>
+where
+    T: Clone + Hash + Eq + Debug + NodeLinkId,
+    G: GraphType,
+{
+    /// Exports a graph to the node-link JSON format used by D3 and NetworkX:
+    /// `{"directed": bool, "nodes": [{"id": ...}], "links": [{"source": ..., "target": ...}]}`.
+    ///
+    /// Numeric nodes are written as JSON numbers and others as strings, see [`NodeLinkId`].
+    /// Every undirected edge is written once.
+    pub fn to_node_link_json(&self) -> String {
+        let nodes: Vec<String> = self
+            .iter()
+            .map(|u| format!("{{\"id\": {}}}", json_id(u)))
+            .collect();
+        let links: Vec<String> = unique_edges(self)
+            .into_iter()
+            .map(|(u, v)| format!("{{\"source\": {}, \"target\": {}}}", json_id(u), json_id(v)))
+            .collect();
+        format!(
+            "{{\"directed\": {}, \"nodes\": [{}], \"links\": [{}]}}",
+            G::is_directed(),
+            nodes.join(", "),
+            links.join(", ")
+        )
+    }
+}
+
+impl<T> Graph<T, Undirected>
+where
+    T: Clone + Hash + Eq + Debug + FromStr,
+{
+    /// Imports an undirected graph from the node-link JSON format.
+    pub fn from_node_link_json(s: &str) -> Result<Self> {
+        let (nodes, links) = parse_node_link(s, false)?;
+        let mut g = Self::new();
+        nodes.into_iter().for_each(|u| g.add_node(u));
+        g.add_edges_from(links);
+        Ok(g)
+    }
+}
+
+impl<T> Graph<T, Directed>
+where
+    T: Clone + Hash + Eq + Debug + FromStr,
+{
+    /// Imports a directed graph from the node-link JSON format.
+    pub fn from_node_link_json(s: &str) -> Result<Self> {
+        let (nodes, links) = parse_node_link(s, true)?;
+        let mut g = Self::new();
+        nodes.into_iter().for_each(|u| g.add_node(u));
+        g.add_edges_from(links);
+        Ok(g)
+    }
+}
+
+/// Formats a value as a CSV field, quoting it if needed. Values with surrounding
+/// whitespace are quoted too, as it is trimmed from unquoted fields on read.
+fn csv_field<T: Display>(value: &T) -> String {
+    let s = value.to_string();
+    if s.contains([',', '"', '\n', '\r']) || s.trim() != s {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s
+    }
+}
+
+/// Reads a CSV record, advancing `line` past any line breaks. Quoted fields may span
+/// lines and are kept verbatim, while unquoted fields are trimmed.
+///
+/// Returns the fields and whether the record is blank.
+fn csv_record(chars: &mut Peekable<Chars>, line: &mut usize) -> Result<(Vec<String>, bool)> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut blank = true;
+    loop {
+        let mut field = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            blank = false;
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => {
+                        *line += usize::from(c == '\n');
+                        field.push(c);
+                    }
+                    None => return Err(ParseError("unterminated quoted field".to_string())),
+                }
+            }
+            while chars.next_if(|c| *c != '\n' && c.is_whitespace()).is_some() {}
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',' && *c != '\n') {
+                field.push(c);
+            }
+            field = field.trim().to_string();
+            blank &= field.is_empty();
+        }
+        fields.push(field);
+
+        match chars.next() {
+            Some(',') => blank = false,
+            Some('\n') => {
+                *line += 1;
+                return Ok((fields, blank));
+            }
+            None => return Ok((fields, blank)),
+            Some(c) => {
+                return Err(ParseError(format!(
+                    "line {}: unexpected '{}' after quoted field",
+                    line, c
+                )))
+            }
+        }
+    }
+}
+
+/// Reads CSV records with exactly `columns` fields, skipping blank lines and optionally a header.
+fn csv_records<R: Read>(mut r: R, has_header: bool, columns: usize) -> Result<Vec<Vec<String>>> {
+    let mut input = String::new();
+    r.read_to_string(&mut input)
+        .map_err(|e| ParseError(e.to_string()))?;
+    let mut chars = input.chars().peekable();
+    let mut records: Vec<Vec<String>> = Vec::new();
+    let mut line = 1;
+    let mut skip_header = has_header;
+    while chars.peek().is_some() {
+        let start = line;
+        let (fields, blank) = csv_record(&mut chars, &mut line)?;
+        if blank || std::mem::take(&mut skip_header) {
+            continue;
+        }
+        if fields.len() != columns {
+            return Err(ParseError(format!(
+                "line {}: expected {} fields, found {}",
+                start,
+                columns,
+                fields.len()
+            )));
+        }
+        records.push(fields);
+    }
+    Ok(records)
+}
+
+/// Parses a CSV field into a value.
+fn parse_field<T: FromStr>(field: &str) -> Result<T> {
+    field
+        .parse::<T>()
+        .map_err(|_| ParseError(format!("invalid value '{}'", field)))
+}
+
+/// Edges of a graph with every undirected edge listed once.
+fn unique_edges<T, G>(g: &Graph<T, G>) -> Vec<(&T, &T)>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let mut edges: Vec<(&T, &T)> = Vec::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+    for u in g.iter() {
+        visited.insert(u);
+        for v in g.adj(u).unwrap() {
+            if G::is_directed() || !visited.contains(v) || u == v {
+                edges.push((u, v));
+            }
+        }
+    }
+    edges
+}
+
+impl<T, G> Graph<T, G>
+where
+    T: Clone + Hash + Eq + Debug + Display,
+    G: GraphType,
+{
+    /// Writes edges of a graph as CSV with a `source,target` header.
+    /// Every undirected edge is written once; isolated nodes are not written.
+    pub fn write_edge_csv<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        writeln!(w, "source,target")?;
+        for (u, v) in unique_edges(self) {
+            writeln!(w, "{},{}", csv_field(u), csv_field(v))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, G, W> WeightedGraph<T, G, W>
+where
+    T: Clone + Hash + Eq + Debug + Display,
+    G: GraphType,
+    W: Weight + Display,
+{
+    /// Writes edges of a graph as CSV with a `source,target,weight` header.
+    /// Every undirected edge is written once; isolated nodes are not written.
+    pub fn write_edge_csv<Wr: Write>(&self, w: &mut Wr) -> std::io::Result<()> {
+        writeln!(w, "source,target,weight")?;
+        for (u, v) in unique_edges(self.graph()) {
+            let weight = self.weight(u, v).unwrap();
+            writeln!(w, "{},{},{}", csv_field(u), csv_field(v), weight)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_read_edge_csv {
+    ($typ:ty) => {
+        impl<T> Graph<T, $typ>
+        where
+            T: Clone + Hash + Eq + Debug + FromStr,
+        {
+            /// Reads a graph from CSV `source,target` records. If `has_header`
+            /// is set, the first non-blank record is skipped.
+            pub fn read_edge_csv<R: Read>(r: R, has_header: bool) -> Result<Self> {
+                let mut g = Self::new();
+                for record in csv_records(r, has_header, 2)? {
+                    g.add_edge(parse_field(&record[0])?, parse_field(&record[1])?);
+                }
+                Ok(g)
+            }
+        }
+
+        impl<T, W> WeightedGraph<T, $typ, W>
+        where
+            T: Clone + Hash + Eq + Debug + FromStr,
+            W: Weight + FromStr,
+        {
+            /// Reads a weighted graph from CSV `source,target,weight` records.
+            /// If `has_header` is set, the first non-blank record is skipped.
+            pub fn read_edge_csv<R: Read>(r: R, has_header: bool) -> Result<Self> {
+                let mut g = Self::new();
+                for record in csv_records(r, has_header, 3)? {
+                    g.add_edge(
+                        parse_field(&record[0])?,
+                        parse_field(&record[1])?,
+                        parse_field(&record[2])?,
+                    );
+                }
+                Ok(g)
+            }
+        }
+    };
+}
+
+impl_read_edge_csv!(Undirected);
+impl_read_edge_csv!(Directed);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DiGraph;
+
+    fn simple_graph() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6)]);
+        g.add_node(7);
+        g
+    }
+
+    #[test]
+    fn undirected_node_link_round_trip() {
+        let g = simple_graph();
+        let json = g.to_node_link_json();
+        assert!(json.starts_with("{\"directed\": false"));
+        assert_eq!(json.matches("\"source\"").count(), 6);
+        assert!(json.contains("{\"id\": 7}"));
+        assert_eq!(Graph::<i8>::from_node_link_json(&json), Ok(g));
+    }
+
+    #[test]
+    fn directed_node_link_round_trip() {
+        let mut g: DiGraph<String> = DiGraph::new();
+        g.add_edge("a".to_string(), "b \"quoted\"".to_string());
+        g.add_edge("b \"quoted\"".to_string(), "a".to_string());
+        g.add_node("42".to_string());
+        let json = g.to_node_link_json();
+        assert!(json.starts_with("{\"directed\": true"));
+        assert_eq!(json.matches("\"source\"").count(), 2);
+        assert_eq!(DiGraph::<String>::from_node_link_json(&json), Ok(g));
+    }
+
+    #[test]
+    fn numeric_looking_string_nodes_stay_strings() {
+        let mut g: Graph<String> = Graph::new();
+        g.add_edge("007".to_string(), "1.".to_string());
+        g.add_edge("1.".to_string(), "12".to_string());
+        let json = g.to_node_link_json();
+        assert!(json.contains("{\"id\": \"007\"}"));
+        assert!(json.contains("{\"id\": \"1.\"}"));
+        assert!(Json::parse(&json).is_ok());
+        assert_eq!(Graph::<String>::from_node_link_json(&json), Ok(g));
+    }
+
+    #[test]
+    fn deeply_nested_json_is_rejected() {
+        let deep = "[".repeat(200_000);
+        assert_eq!(
+            Json::parse(&deep),
+            Err(ParseError("nesting too deep".to_string()))
+        );
+        let nested = format!("{}{}", "[".repeat(100), "]".repeat(100));
+        assert!(Json::parse(&nested).is_ok());
+    }
+
+    #[test]
+    fn surrogate_pair_escapes() {
+        let json = r#"{"directed": false, "nodes": [{"id": "\ud83d\ude00"}, {"id": "a\u00e9"}],
+            "links": [{"source": "\ud83d\ude00", "target": "a\u00e9"}]}"#;
+        let g = Graph::<String>::from_node_link_json(json).unwrap();
+        assert!(g.has_edge(&"\u{1f600}".to_string(), &"a\u{e9}".to_string()));
+        assert_eq!(
+            Graph::<String>::from_node_link_json(&g.to_node_link_json()),
+            Ok(g)
+        );
+
+        assert!(Json::parse(r#""\ud83d""#).is_err());
+        assert!(Json::parse(r#""\ud83d\u0041""#).is_err());
+        assert!(Json::parse(r#""\ude00""#).is_err());
+        assert!(Json::parse(r#""\u12""#).is_err());
+    }
+
+    #[test]
+    fn node_link_import() {
+        let json = r#"{
+            "directed": false, "multigraph": false, "graph": {},
+            "nodes": [{"id": 1}, {"id": 2}, {"id": 3}],
+            "links": [{"source": 1, "target": 2, "weight": 1.5}]
+        }"#;
+        let g = Graph::<i8>::from_node_link_json(json).unwrap();
+        assert_eq!(g.node_count(), 3);
+        assert!(g.has_edge(&2, &1));
+    }
+
+    #[test]
+    fn edge_csv_round_trip() {
+        let g = simple_graph();
+        let mut buffer: Vec<u8> = Vec::new();
+        g.write_edge_csv(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert!(text.starts_with("source,target\n"));
+        assert_eq!(text.lines().count(), 7);
+
+        let mut expected = g.clone();
+        expected.remove_node(&7);
+        assert_eq!(Graph::<i8>::read_edge_csv(&buffer[..], true), Ok(expected));
+    }
+
+    #[test]
+    fn directed_edge_csv_round_trip() {
+        let mut g: DiGraph<String> = DiGraph::new();
+        g.add_edge("a,b".to_string(), "c \"d\"".to_string());
+        g.add_edge("c \"d\"".to_string(), "a,b".to_string());
+        let mut buffer: Vec<u8> = Vec::new();
+        g.write_edge_csv(&mut buffer).unwrap();
+        assert_eq!(DiGraph::<String>::read_edge_csv(&buffer[..], true), Ok(g));
+    }
+
+    #[test]
+    fn multiline_and_padded_csv_round_trip() {
+        let mut g: DiGraph<String> = DiGraph::new();
+        g.add_edge("line\nbreak".to_string(), "  padded ".to_string());
+        g.add_edge("  padded ".to_string(), "\r\n".to_string());
+        g.add_edge("plain".to_string(), " ".to_string());
+        let mut buffer: Vec<u8> = Vec::new();
+        g.write_edge_csv(&mut buffer).unwrap();
+        assert_eq!(DiGraph::<String>::read_edge_csv(&buffer[..], true), Ok(g));
+
+        let csv = "\"a\nb\",c\n\"d\" x,e\n";
+        let err = DiGraph::<String>::read_edge_csv(csv.as_bytes(), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse a graph: line 3: unexpected 'x' after quoted field"
+        );
+        assert!(DiGraph::<String>::read_edge_csv("\"a,b\n".as_bytes(), false).is_err());
+    }
+
+    #[test]
+    fn weighted_edge_csv_round_trip() {
+        let mut g: WeightedGraph<i8, Directed, f64> = WeightedGraph::new();
+        g.add_edge(1, 2, 0.5);
+        g.add_edge(2, 3, 1.25);
+        let mut buffer: Vec<u8> = Vec::new();
+        g.write_edge_csv(&mut buffer).unwrap();
+        assert!(buffer.starts_with(b"source,target,weight\n"));
+
+        let h = WeightedGraph::<i8, Directed, f64>::read_edge_csv(&buffer[..], true).unwrap();
+        assert_eq!(h.weight(&1, &2), Some(0.5));
+        assert_eq!(h.weight(&2, &3), Some(1.25));
+        assert_eq!(h.weight(&2, &1), None);
+    }
+
+    #[test]
+    fn edge_csv_without_header() {
+        let csv = "1,2\n\n2, 3\n";
+        let g = Graph::<i8>::read_edge_csv(csv.as_bytes(), false).unwrap();
+        assert_eq!(g.edge_count(), 2);
+        assert!(Graph::<i8>::read_edge_csv(csv.as_bytes(), true)
+            .unwrap()
+            .has_edge(&3, &2));
+        assert!(Graph::<i8>::read_edge_csv("source,target\n".as_bytes(), false).is_err());
+        let leading_blank = "\n \nsource,target\n1,2\n";
+        let g = Graph::<i8>::read_edge_csv(leading_blank.as_bytes(), true).unwrap();
+        assert!(g.has_edge(&1, &2));
+        assert!(Graph::<i8>::read_edge_csv("1,2,3\n".as_bytes(), false).is_err());
+    }
+
+    #[test]
+    fn node_link_errors() {
+        let g = simple_graph();
+        assert!(DiGraph::<i8>::from_node_link_json(&g.to_node_link_json()).is_err());
+        assert!(Graph::<i8>::from_node_link_json("{\"directed\": false}").is_err());
+        assert!(Graph::<i8>::from_node_link_json("[1, 2").is_err());
+        assert!(Graph::<i8>::from_node_link_json(
+            r#"{"directed": false, "nodes": [{"id": "x"}], "links": []}"#
+        )
+        .is_err());
+    }
+}
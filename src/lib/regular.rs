@@ -0,0 +1,97 @@
+//! Degree summaries, and regular and complete graphs.
+use crate::graph::{Graph, GraphType, Undirected};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Returns the average degree of a graph, i.e. `2m / n`, or `m / n` (the average
+/// out-degree) for a directed graph. Returns 0 for the empty graph.
+pub fn average_degree<T, G>(g: &Graph<T, G>) -> f64
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let n = g.node_count();
+    if n == 0 {
+        return 0.0;
+    }
+    let ends = if G::is_directed() { 1 } else { 2 };
+    (ends * g.edge_count()) as f64 / n as f64
+}
+
+/// Returns `true` if all nodes have the same degree.
+pub fn is_regular<T>(g: &Graph<T, Undirected>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut degrees = g.iter().map(|u| g.degree(u));
+    match degrees.next() {
+        Some(d) => degrees.all(|e| e == d),
+        None => true,
+    }
+}
+
+/// Returns `true` if every pair of distinct nodes is adjacent and there are no self-loops.
+pub fn is_complete<T>(g: &Graph<T, Undirected>) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let n = g.node_count();
+    g.iter().all(|u| {
+        let adj = g.adj(u).unwrap();
+        adj.len() + 1 == n && !adj.contains(u)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DiGraph;
+
+    #[test]
+    fn average_degrees() {
+        let mut cycle: Graph<i8> = Graph::new();
+        cycle.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+        assert_eq!(average_degree(&cycle), 2.0);
+
+        let mut star: Graph<i8> = Graph::new();
+        star.add_edges_from(vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+        assert_eq!(average_degree(&star), 1.6);
+        star.add_edge(0, 0);
+        assert_eq!(average_degree(&star), 2.0);
+
+        let mut directed: DiGraph<i8> = DiGraph::new();
+        directed.add_edges_from(vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+        assert_eq!(average_degree(&directed), 0.8);
+        assert_eq!(average_degree(&Graph::<i8>::new()), 0.0);
+    }
+
+    #[test]
+    fn cycle_is_regular() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+        assert!(is_regular(&g));
+        assert!(!is_complete(&g));
+
+        g.add_edge(1, 3);
+        assert!(!is_regular(&g));
+    }
+
+    #[test]
+    fn k4_is_complete() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]);
+        assert!(is_regular(&g));
+        assert!(is_complete(&g));
+
+        g.add_edge(4, 4);
+        assert!(!is_complete(&g));
+    }
+
+    #[test]
+    fn single_node() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_node(1);
+        assert!(is_regular(&g));
+        assert!(is_complete(&g));
+    }
+}
@@ -1,8 +1,11 @@
 //! Path searching in a graph.
-use crate::graph::{Graph, GraphType};
+use crate::graph::{DiGraph, Directed, Graph, GraphType, Undirected};
+use crate::random::Rng;
+use crate::sort::has_cycle;
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
-use std::fmt::Debug;
+use std::collections::{hash_set, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
 /// Breadth-first search (BFS) algorithm.
@@ -13,6 +16,12 @@ pub struct BFS {}
 #[derive(Debug)]
 pub struct Dijkstra {}
 
+/// A shortest path search algorithm.
+///
+/// Implementors must provide [`Self::shortest_path_util`]. The provided [`Self::search`],
+/// on which most other methods build, is only a slow fallback: it runs
+/// `shortest_path_util` once per node, making every search `n` times as costly.
+/// Implementors should override it with a single traversal, as [`BFS`] and [`Dijkstra`] do.
 pub trait SearchAlgorithm<T, G>
 where
     T: Clone + Hash + Eq + Debug,
@@ -21,24 +30,120 @@ where
     /// A utility function for finding a shortest path in a graph.
     fn shortest_path_util(g: &Graph<T, G>, source: T, target: T) -> Option<(usize, HashMap<T, T>)>;
 
+    /// Searches a graph `g` starting from `source`, calling `on_settle` with every
+    /// node whose distance becomes final, in order of increasing distance. The
+    /// search stops as soon as `on_settle` returns `true`.
+    ///
+    /// Returns a map from each discovered node to its predecessor.
+    ///
+    /// The default implementation is a slow fallback which runs
+    /// [`Self::shortest_path_util`] once per node, so implementors should override it
+    /// with a single traversal.
+    fn search<F>(g: &Graph<T, G>, source: T, mut on_settle: F) -> HashMap<T, T>
+    where
+        F: FnMut(&T, usize) -> bool,
+    {
+        let mut settled: Vec<(usize, T, Option<T>)> = g
+            .iter()
+            .filter_map(|v| {
+                let (dist, mut previous) = Self::shortest_path_util(g, source.clone(), v.clone())?;
+                Some((dist, v.clone(), previous.remove(v)))
+            })
+            .collect();
+        settled.sort_by_key(|(dist, _, _)| *dist);
+
+        let mut previous: HashMap<T, T> = HashMap::new();
+        for (dist, node, pred) in settled {
+            if let Some(pred) = pred {
+                previous.insert(node.clone(), pred);
+            }
+            if on_settle(&node, dist) {
+                break;
+            }
+        }
+        previous
+    }
+
     /// A shortest path between a `source` and a `target` nodes in a graph `g`.
     fn shortest_path(g: &Graph<T, G>, source: T, target: T) -> Option<Vec<T>> {
         Self::shortest_path_util(g, source.clone(), target.clone())
             .map(|(_, mut previous)| build_path::<T>(&mut previous, source, target))
     }
 
+    /// A shortest path between a `source` and a `target` nodes, calling `on_visit` with every
+    /// settled node and its distance. The path is the same as the one from [`Self::shortest_path`].
+    fn shortest_path_with_observer<F>(
+        g: &Graph<T, G>,
+        source: T,
+        target: T,
+        mut on_visit: F,
+    ) -> Option<Vec<T>>
+    where
+        F: FnMut(&T, usize),
+    {
+        let mut found = false;
+        let mut previous = Self::search(g, source.clone(), |node, dist| {
+            on_visit(node, dist);
+            found = *node == target;
+            found
+        });
+        found.then(|| build_path::<T>(&mut previous, source, target))
+    }
+
     /// A shortest path's length.
     fn shortest_path_length(g: &Graph<T, G>, source: T, target: T) -> Option<usize> {
         Self::shortest_path_util(g, source, target).map(|(len, _)| len)
     }
 
     /// Returns `True` if `g` has a path from `source` to `target`
+    ///
+    /// Only reachability matters here, so a plain traversal is used that stops
+    /// as soon as `target` is seen and keeps no predecessor map.
     fn has_path(g: &Graph<T, G>, source: T, target: T) -> bool {
-        Self::shortest_path_util(g, source, target).is_some()
+        if source == target {
+            return true;
+        }
+        let mut visited: HashSet<&T> = HashSet::from([&source]);
+        let mut stack: Vec<&T> = vec![&source];
+
+        while let Some(node) = stack.pop() {
+            for neighbor in g.adj(node).expect("Node does not exist") {
+                if *neighbor == target {
+                    return true;
+                }
+                if visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        false
+    }
+
+    /// A tree of shortest paths rooted at `source`, mapping every other reachable
+    /// node to its predecessor on a shortest path from `source`.
+    fn shortest_path_tree(g: &Graph<T, G>, source: T) -> HashMap<T, T> {
+        Self::search(g, source, |_, _| false)
+    }
+
+    /// Shortest paths' lengths from `source` to each of `targets` found in a single search.
+    /// Unreachable targets are omitted.
+    fn shortest_path_lengths(g: &Graph<T, G>, source: T, targets: &[T]) -> HashMap<T, usize> {
+        let mut remaining: HashSet<&T> = targets.iter().collect();
+        let mut lengths: HashMap<T, usize> = HashMap::new();
+
+        if !remaining.is_empty() {
+            Self::search(g, source, |node, dist| {
+                if remaining.remove(node) {
+                    lengths.insert(node.clone(), dist);
+                }
+                remaining.is_empty()
+            });
+        }
+        lengths
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone)]
 struct State<T>
 where
     T: Clone + Hash + Eq + Debug,
@@ -49,26 +154,34 @@ where
 
 impl<T> Ord for State<T>
 where
-    T: Clone + Hash + Eq + Debug + Ord,
+    T: Clone + Hash + Eq + Debug,
 {
     fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .cost
-            .cmp(&self.cost)
-            .then_with(|| self.node.cmp(&other.node))
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl<T> PartialEq for State<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
     }
 }
 
+impl<T> Eq for State<T> where T: Clone + Hash + Eq + Debug {}
+
 impl<T> PartialOrd for State<T>
 where
-    T: Clone + Hash + Eq + Debug + Ord,
+    T: Clone + Hash + Eq + Debug,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-fn build_path<T>(previous: &mut HashMap<T, T>, source: T, target: T) -> Vec<T>
+pub(crate) fn build_path<T>(previous: &mut HashMap<T, T>, source: T, target: T) -> Vec<T>
 where
     T: Clone + Hash + Eq + Debug,
 {
@@ -82,23 +195,47 @@ where
     path.into_iter().rev().collect()
 }
 
+/// Finds a shortest path with [`SearchAlgorithm::search`], stopping at `target`.
+fn shortest_path_by_search<S, T, G>(
+    g: &Graph<T, G>,
+    source: T,
+    target: T,
+) -> Option<(usize, HashMap<T, T>)>
+where
+    S: SearchAlgorithm<T, G> + ?Sized,
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let mut length: Option<usize> = None;
+    let previous = S::search(g, source, |node, dist| {
+        if *node == target {
+            length = Some(dist);
+        }
+        length.is_some()
+    });
+    length.map(|len| (len, previous))
+}
+
 impl<T, G> SearchAlgorithm<T, G> for BFS
 where
     T: Clone + Hash + Eq + Debug,
     G: GraphType,
 {
     fn shortest_path_util(g: &Graph<T, G>, source: T, target: T) -> Option<(usize, HashMap<T, T>)> {
+        shortest_path_by_search::<Self, T, G>(g, source, target)
+    }
+
+    fn search<F>(g: &Graph<T, G>, source: T, mut on_settle: F) -> HashMap<T, T>
+    where
+        F: FnMut(&T, usize) -> bool,
+    {
         let mut previous: HashMap<T, T> = HashMap::new();
         let mut visited: HashSet<T> = HashSet::from_iter(vec![source.clone()]);
-        let mut queue: VecDeque<(T, usize)> = VecDeque::from_iter(vec![(source.clone(), 0)]);
-
-        while queue.len() > 0 {
-            let node: T;
-            let dist: usize;
-            (node, dist) = queue.pop_front().expect("Empty queue");
+        let mut queue: VecDeque<(T, usize)> = VecDeque::from_iter(vec![(source, 0)]);
 
-            if node == target {
-                return Some((dist, previous));
+        while let Some((node, dist)) = queue.pop_front() {
+            if on_settle(&node, dist) {
+                break;
             }
 
             for neighbor in g.adj(&node).expect("Node does not exist") {
@@ -109,51 +246,569 @@ where
                 }
             }
         }
-        None
+        previous
     }
 }
 
 impl<T, G> SearchAlgorithm<T, G> for Dijkstra
 where
-    T: Clone + Hash + Eq + Debug + Ord,
+    T: Clone + Hash + Eq + Debug,
     G: GraphType,
 {
     fn shortest_path_util(g: &Graph<T, G>, source: T, target: T) -> Option<(usize, HashMap<T, T>)> {
-        let mut dist: HashMap<T, usize> = g
-            .nodes::<Vec<_>>()
-            .iter()
-            .map(|x| ((*x).clone(), usize::MAX))
-            .collect();
+        shortest_path_by_search::<Self, T, G>(g, source, target)
+    }
+
+    fn search<F>(g: &Graph<T, G>, source: T, mut on_settle: F) -> HashMap<T, T>
+    where
+        F: FnMut(&T, usize) -> bool,
+    {
+        // Distances are recorded lazily; a missing node has not been reached yet.
+        let mut dist: HashMap<T, usize> = HashMap::from([(source.clone(), 0)]);
         let mut previous: HashMap<T, T> = HashMap::new();
         let mut heap: BinaryHeap<State<T>> = BinaryHeap::from([State {
             cost: 0,
             node: source.clone(),
         }]);
-        *dist.get_mut(&source).unwrap() = 0;
 
         while let Some(State { cost, node }) = heap.pop() {
-            if node == target {
-                return Some((cost, previous));
-            }
             if cost > dist[&node] {
                 continue;
             }
+            if on_settle(&node, cost) {
+                break;
+            }
             for neighbor in g.adj(&node).expect("No such node in a graph") {
                 let next = State {
                     cost: cost + 1,
                     node: neighbor.clone(),
                 };
-                if next.cost < dist[&neighbor] {
-                    *dist.get_mut(neighbor).unwrap() = next.cost;
+                if dist.get(neighbor).is_none_or(|d| next.cost < *d) {
+                    dist.insert(neighbor.clone(), next.cost);
                     heap.push(next);
                     previous.insert(neighbor.clone(), node.clone());
                 }
             }
         }
+        previous
+    }
+}
+
+/// A shortest path between a `source` and a `target` nodes found with Dijkstra's
+/// algorithm, which gives up once the cheapest unexplored node costs more than `max_cost`.
+pub fn shortest_path_within<T, G>(
+    g: &Graph<T, G>,
+    source: T,
+    target: T,
+    max_cost: usize,
+) -> Option<Vec<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let mut found = false;
+    let mut previous = Dijkstra::search(g, source.clone(), |node, cost| {
+        found = cost <= max_cost && *node == target;
+        found || cost > max_cost
+    });
+    found.then(|| build_path(&mut previous, source, target))
+}
+
+/// Computes shortest paths between all pairs of nodes with the Floyd–Warshall algorithm.
+///
+/// Returns distances between every pair of nodes `(u, v)` such that `v` is
+/// reachable from `u`, and for every such pair with `u != v` the node following
+/// `u` on a shortest path to `v`. Use [`reconstruct_path`] to obtain the paths.
+#[allow(clippy::type_complexity)]
+pub fn all_pairs_paths<T, G>(g: &Graph<T, G>) -> (HashMap<(T, T), usize>, HashMap<(T, T), T>)
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let nodes: Vec<&T> = g.iter().collect();
+    let index: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let n = nodes.len();
+    let mut dist: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n];
+    let mut next: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n];
+
+    for (i, node) in nodes.iter().enumerate() {
+        dist[i][i] = Some(0);
+        for neighbor in g.adj(node).unwrap() {
+            let j = index[neighbor];
+            if i != j {
+                dist[i][j] = Some(1);
+                next[i][j] = Some(j);
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(dik) = dist[i][k] else {
+                continue;
+            };
+            for j in 0..n {
+                let Some(dkj) = dist[k][j] else {
+                    continue;
+                };
+                if dist[i][j].is_none_or(|dij| dik + dkj < dij) {
+                    dist[i][j] = Some(dik + dkj);
+                    next[i][j] = next[i][k];
+                }
+            }
+        }
+    }
+
+    let mut distances: HashMap<(T, T), usize> = HashMap::new();
+    let mut successors: HashMap<(T, T), T> = HashMap::new();
+    for i in 0..n {
+        for j in 0..n {
+            let key = (nodes[i].clone(), nodes[j].clone());
+            if let Some(d) = dist[i][j] {
+                distances.insert(key.clone(), d);
+            }
+            if let Some(k) = next[i][j] {
+                successors.insert(key, nodes[k].clone());
+            }
+        }
+    }
+    (distances, successors)
+}
+
+/// Reconstructs a shortest path from `u` to `v` using successors computed by [`all_pairs_paths`].
+/// Returns `None` if there is no path. A path from a node to itself consists of that node only.
+pub fn reconstruct_path<T>(next: &HashMap<(T, T), T>, u: T, v: T) -> Option<Vec<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut path: Vec<T> = vec![u.clone()];
+    let mut current = u;
+    while current != v {
+        current = next.get(&(current, v.clone()))?.clone();
+        path.push(current.clone());
+    }
+    Some(path)
+}
+
+/// Returns nodes whose shortest path distance from `source` is exactly `k`.
+/// Returns an empty set if `source` is not in the graph.
+pub fn nodes_at_distance<T, G>(g: &Graph<T, G>, source: T, k: usize) -> HashSet<T>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let mut layer: HashSet<T> = HashSet::new();
+    if g.adj(&source).is_none() {
+        return layer;
+    }
+    BFS::search(g, source, |node, dist| {
+        if dist == k {
+            layer.insert(node.clone());
+        }
+        dist > k
+    });
+    layer
+}
+
+/// A shortest path between a `source` and a `target` nodes found with BFS, as references
+/// to nodes stored in a graph. Unlike [`SearchAlgorithm::shortest_path`] no node is cloned.
+pub fn shortest_path_refs<'a, T, G>(
+    g: &'a Graph<T, G>,
+    source: &T,
+    target: &T,
+) -> Option<Vec<&'a T>>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let source = g.node_ref(source)?;
+    let mut previous: HashMap<&T, &T> = HashMap::new();
+    let mut visited: HashSet<&T> = HashSet::from([source]);
+    let mut queue: VecDeque<&T> = VecDeque::from([source]);
+
+    while let Some(node) = queue.pop_front() {
+        if node == target {
+            let mut path = vec![node];
+            while let Some(prev) = previous.get(path.last().unwrap()) {
+                path.push(prev);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for neighbor in g.adj(node).unwrap() {
+            if visited.insert(neighbor) {
+                previous.insert(neighbor, node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    None
+}
+
+/// A shortest path to a `target` node from whichever of `sources` is closest, together
+/// with its length. Unknown sources are ignored.
+///
+/// Runs a single BFS whose frontier starts with all sources at distance 0.
+pub fn multi_source_shortest_path<T, G>(
+    g: &Graph<T, G>,
+    sources: &[T],
+    target: T,
+) -> Option<(usize, Vec<T>)>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let mut previous: HashMap<T, T> = HashMap::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+    let mut queue: VecDeque<(&T, usize)> = VecDeque::new();
+    for source in sources.iter().filter_map(|u| g.node_ref(u)) {
+        if visited.insert(source) {
+            queue.push_back((source, 0));
+        }
+    }
+
+    while let Some((node, dist)) = queue.pop_front() {
+        if *node == target {
+            let mut path = vec![target];
+            while let Some(prev) = previous.get(path.last().unwrap()) {
+                path.push(prev.clone());
+            }
+            path.reverse();
+            return Some((dist, path));
+        }
+        for neighbor in g.adj(node).unwrap() {
+            if visited.insert(neighbor) {
+                previous.insert(neighbor.clone(), node.clone());
+                queue.push_back((neighbor, dist + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the subgraph induced on nodes within `radius` hops from `center`.
+pub fn ego_graph<T, G>(g: &Graph<T, G>, center: T, radius: usize) -> Graph<T, G>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    let mut nodes: HashSet<T> = HashSet::new();
+    if g.adj(&center).is_some() {
+        BFS::search(g, center, |node, dist| {
+            if dist <= radius {
+                nodes.insert(node.clone());
+            }
+            dist > radius
+        });
+    }
+    g.subgraph(&nodes)
+}
+
+/// Returns the nodes that can reach `target` in at most `radius` hops.
+///
+/// The `target` itself is not included.
+pub fn ancestors_within<T>(g: &Graph<T, Directed>, target: T, radius: usize) -> HashSet<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut seen: HashSet<T> = HashSet::new();
+    if g.adj(&target).is_none() {
+        return seen;
+    }
+    let mut queue = VecDeque::from([(target.clone(), 0)]);
+    seen.insert(target.clone());
+
+    while let Some((node, dist)) = queue.pop_front() {
+        if dist == radius {
+            continue;
+        }
+        for prev in g.pred(&node).into_iter().flatten() {
+            if seen.insert(prev.clone()) {
+                queue.push_back((prev.clone(), dist + 1));
+            }
+        }
+    }
+    seen.remove(&target);
+    seen
+}
+
+/// Returns the subgraph induced on neighbors of `u`, including `u` itself if `closed`.
+/// An unknown `u` yields an empty graph.
+pub fn neighborhood_subgraph<T>(
+    g: &Graph<T, Undirected>,
+    u: &T,
+    closed: bool,
+) -> Graph<T, Undirected>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut nodes: HashSet<T> = g.adj(u).cloned().unwrap_or_default();
+    if closed {
+        nodes.insert(u.clone());
+    } else {
+        nodes.remove(u);
+    }
+    g.subgraph(&nodes)
+}
+
+/// A lazy depth-first traversal over edges, see [`Graph::edge_dfs`].
+pub struct EdgeDfs<'a, T, G>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    graph: &'a Graph<T, G>,
+    stack: Vec<&'a T>,
+    neighbors: HashMap<&'a T, hash_set::Iter<'a, T>>,
+    visited: HashSet<(&'a T, &'a T)>,
+}
+
+impl<'a, T, G> Iterator for EdgeDfs<'a, T, G>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&node) = self.stack.last() {
+            let graph = self.graph;
+            let neighbors = self
+                .neighbors
+                .entry(node)
+                .or_insert_with(|| graph.adj(node).unwrap().iter());
+            match neighbors.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(next) => {
+                    let seen = self.visited.contains(&(node, next))
+                        || (!G::is_directed() && self.visited.contains(&(next, node)));
+                    if !seen {
+                        self.visited.insert((node, next));
+                        self.stack.push(next);
+                        return Some((node.clone(), next.clone()));
+                    }
+                }
+            }
+        }
         None
     }
 }
 
+impl<T, G> Graph<T, G>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    /// Iterate over edges reachable from `source` in the order a depth-first search traverses them.
+    /// Every edge is yielded once, whether it leads to a new node or not.
+    pub fn edge_dfs(&self, source: T) -> EdgeDfs<'_, T, G> {
+        EdgeDfs {
+            graph: self,
+            stack: self.node_ref(&source).into_iter().collect(),
+            neighbors: HashMap::new(),
+            visited: HashSet::new(),
+        }
+    }
+}
+
+/// Builds a directed tree from a map of child to parent, with edges pointing parent to child.
+fn tree_from_parents<T, I>(root: T, parents: I) -> DiGraph<T>
+where
+    T: Clone + Hash + Eq + Debug,
+    I: IntoIterator<Item = (T, T)>,
+{
+    let mut tree: DiGraph<T> = DiGraph::new();
+    tree.add_node(root);
+    for (child, parent) in parents {
+        tree.add_edge(parent, child);
+    }
+    tree
+}
+
+/// An error returned when a parent map does not describe a tree rooted at a given node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParentMapError {
+    /// Following parents leads to a cycle.
+    Cycle,
+    /// The root has a parent.
+    RootHasParent,
+    /// Following parents from some node ends at a node other than the root.
+    Unrooted,
+}
+
+impl std::error::Error for ParentMapError {}
+
+impl Display for ParentMapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParentMapError::Cycle => write!(f, "A cycle has been detected in a parent map"),
+            ParentMapError::RootHasParent => write!(f, "The root has a parent"),
+            ParentMapError::Unrooted => write!(f, "Some node does not descend from the root"),
+        }
+    }
+}
+
+/// Builds a directed tree from a map of child to parent, e.g. one returned by
+/// [`SearchAlgorithm::shortest_path_tree`], with edges pointing parent to child.
+///
+/// Returns a `ParentMapError` unless following parents from every node ends at `root`,
+/// which itself has no parent.
+pub fn from_parent_map<T>(
+    parents: &HashMap<T, T>,
+    root: T,
+) -> Result<Graph<T, Directed>, ParentMapError>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    if parents.contains_key(&root) {
+        return Err(ParentMapError::RootHasParent);
+    }
+    let tree = tree_from_parents(
+        root.clone(),
+        parents.iter().map(|(u, v)| (u.clone(), v.clone())),
+    );
+    let mut reached = 0;
+    BFS::search(&tree, root, |_, _| {
+        reached += 1;
+        false
+    });
+    if reached == tree.node_count() {
+        Ok(tree)
+    } else if has_cycle(&tree) {
+        Err(ParentMapError::Cycle)
+    } else {
+        Err(ParentMapError::Unrooted)
+    }
+}
+
+/// Maps every node reachable from `source` to its parent in a breadth-first search,
+/// or to `None` for the `source`. An unknown `source` yields an empty map.
+pub fn bfs_predecessors<T, G>(g: &Graph<T, G>, source: T) -> HashMap<T, Option<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    if g.adj(&source).is_none() {
+        return HashMap::new();
+    }
+    let mut predecessors: HashMap<T, Option<T>> = BFS::shortest_path_tree(g, source.clone())
+        .into_iter()
+        .map(|(u, v)| (u, Some(v)))
+        .collect();
+    predecessors.insert(source, None);
+    predecessors
+}
+
+/// Returns the tree of edges used by a breadth-first search from `root`,
+/// pointing from parent to child. An unknown `root` yields an empty graph.
+pub fn bfs_tree<T, G>(g: &Graph<T, G>, root: T) -> DiGraph<T>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    if g.adj(&root).is_none() {
+        return DiGraph::new();
+    }
+    let parents = BFS::shortest_path_tree(g, root.clone());
+    tree_from_parents(root, parents)
+}
+
+/// Returns the tree of edges used by a depth-first search from `root`,
+/// pointing from parent to child. An unknown `root` yields an empty graph.
+pub fn dfs_tree<T, G>(g: &Graph<T, G>, root: T) -> DiGraph<T>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    if g.adj(&root).is_none() {
+        return DiGraph::new();
+    }
+    let mut parents: HashMap<T, T> = HashMap::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+    let mut stack: Vec<(&T, Option<&T>)> = vec![(&root, None)];
+
+    while let Some((node, parent)) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        if let Some(parent) = parent {
+            parents.insert(node.clone(), parent.clone());
+        }
+        for neighbor in g.adj(node).unwrap() {
+            if !visited.contains(neighbor) {
+                stack.push((neighbor, Some(node)));
+            }
+        }
+    }
+    tree_from_parents(root.clone(), parents)
+}
+
+/// A shortest path between a `source` and a `target` nodes chosen at random,
+/// but reproducibly for a given `seed`, among all shortest paths.
+///
+/// Every shortest path is equally likely to be chosen. `T: Ord` is required so
+/// that the choice does not depend on the hash iteration order.
+pub fn shortest_path_seeded<T, G>(
+    g: &Graph<T, G>,
+    source: T,
+    target: T,
+    seed: u64,
+) -> Option<Vec<T>>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+    G: GraphType,
+{
+    let mut dist: HashMap<T, usize> = HashMap::from([(source.clone(), 0)]);
+    let mut sigma: HashMap<T, f64> = HashMap::from([(source.clone(), 1.0)]);
+    let mut predecessors: HashMap<T, Vec<T>> = HashMap::new();
+    let mut queue: VecDeque<T> = VecDeque::from([source.clone()]);
+
+    while let Some(node) = queue.pop_front() {
+        if node == target {
+            break;
+        }
+        for neighbor in g.adj(&node).expect("Node does not exist") {
+            if !dist.contains_key(neighbor) {
+                dist.insert(neighbor.clone(), dist[&node] + 1);
+                queue.push_back(neighbor.clone());
+            }
+            if dist[neighbor] == dist[&node] + 1 {
+                *sigma.entry(neighbor.clone()).or_default() += sigma[&node];
+                predecessors
+                    .entry(neighbor.clone())
+                    .or_default()
+                    .push(node.clone());
+            }
+        }
+    }
+
+    if !dist.contains_key(&target) {
+        return None;
+    }
+
+    let mut rng = Rng::new(seed);
+    let mut path: Vec<T> = vec![target.clone()];
+    let mut current = target;
+
+    while current != source {
+        let mut candidates = predecessors.remove(&current).expect("No entry");
+        candidates.sort();
+        let mut pick = rng.next_f64() * sigma[&current];
+        current = candidates
+            .iter()
+            .find(|p| {
+                pick -= sigma[*p];
+                pick < 0.0
+            })
+            .unwrap_or(candidates.last().unwrap())
+            .clone();
+        path.push(current.clone());
+    }
+    Some(path.into_iter().rev().collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,6 +868,303 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn bfs_shortest_path_lengths() {
+        let g = simple_graph();
+        let targets = [2, 4, 6, 7, 8];
+        let actual = BFS::shortest_path_lengths(&g, 1, &targets);
+        let expected = targets
+            .iter()
+            .filter_map(|t| BFS::shortest_path_length(&g, 1, *t).map(|len| (*t, len)))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(actual, expected);
+        assert_eq!(actual, HashMap::from([(2, 1), (4, 2), (6, 3)]));
+    }
+
+    #[test]
+    fn dijkstra_shortest_path_lengths() {
+        let g = simple_graph();
+        let targets = [1, 3, 6];
+        let actual = Dijkstra::shortest_path_lengths(&g, 1, &targets);
+        let expected = targets
+            .iter()
+            .filter_map(|t| Dijkstra::shortest_path_length(&g, 1, *t).map(|len| (*t, len)))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(actual, expected);
+        assert!(Dijkstra::shortest_path_lengths(&g, 1, &[]).is_empty());
+    }
+
+    #[test]
+    fn has_path_matches_shortest_path_util() {
+        let g = simple_graph();
+        for u in 1..=7 {
+            for v in 1..=7 {
+                assert_eq!(
+                    BFS::has_path(&g, u, v),
+                    BFS::shortest_path_util(&g, u, v).is_some()
+                );
+                assert_eq!(
+                    Dijkstra::has_path(&g, u, v),
+                    Dijkstra::shortest_path_util(&g, u, v).is_some()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bfs_shortest_path_tree() {
+        let g = simple_graph();
+        let tree = BFS::shortest_path_tree(&g, 1);
+        assert!(!tree.contains_key(&1));
+        assert!(!tree.contains_key(&7));
+        assert_eq!(build_path(&mut tree.clone(), 1, 6), vec![1, 5, 4, 6]);
+        assert_eq!(build_path(&mut tree.clone(), 1, 3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn default_search_uses_shortest_path_util() {
+        struct UtilOnly;
+
+        impl SearchAlgorithm<i8, Undirected> for UtilOnly {
+            fn shortest_path_util(
+                g: &Graph<i8>,
+                source: i8,
+                target: i8,
+            ) -> Option<(usize, HashMap<i8, i8>)> {
+                BFS::shortest_path_util(g, source, target)
+            }
+        }
+
+        let g = simple_graph();
+        let tree = UtilOnly::shortest_path_tree(&g, 1);
+        assert_eq!(tree.len(), 5);
+        assert_eq!(build_path(&mut tree.clone(), 1, 6), vec![1, 5, 4, 6]);
+        let lengths = UtilOnly::shortest_path_lengths(&g, 1, &[3, 6, 7]);
+        assert_eq!(lengths, HashMap::from([(3, 2), (6, 3)]));
+        assert_eq!(UtilOnly::shortest_path(&g, 1, 4), Some(vec![1, 5, 4]));
+    }
+
+    #[test]
+    fn dijkstra_shortest_path_tree() {
+        let g = simple_graph();
+        let tree = Dijkstra::shortest_path_tree(&g, 6);
+        assert_eq!(tree.len(), 5);
+        assert_eq!(build_path(&mut tree.clone(), 6, 1), vec![6, 4, 5, 1]);
+        assert_eq!(build_path(&mut tree.clone(), 6, 2), vec![6, 4, 3, 2]);
+    }
+
+    #[test]
+    fn bounded_dijkstra() {
+        let g = simple_graph();
+        assert_eq!(shortest_path_within(&g, 1, 6, 3), Some(vec![1, 5, 4, 6]));
+        assert_eq!(shortest_path_within(&g, 1, 6, 2), None);
+        assert_eq!(shortest_path_within(&g, 1, 7, 10), None);
+        assert_eq!(shortest_path_within(&g, 1, 1, 0), Some(vec![1]));
+    }
+
+    #[test]
+    fn floyd_warshall_paths() {
+        let g = simple_graph();
+        let (dist, next) = all_pairs_paths(&g);
+        assert_eq!(dist[&(1, 6)], 3);
+        assert_eq!(dist[&(3, 3)], 0);
+        assert!(!dist.contains_key(&(1, 7)));
+        assert_eq!(reconstruct_path(&next, 1, 6), BFS::shortest_path(&g, 1, 6));
+        assert_eq!(reconstruct_path(&next, 6, 2), Some(vec![6, 4, 3, 2]));
+        assert_eq!(reconstruct_path(&next, 7, 7), Some(vec![7]));
+        assert_eq!(reconstruct_path(&next, 1, 7), None);
+    }
+
+    #[test]
+    fn test_nodes_at_distance() {
+        let g = simple_graph();
+        assert_eq!(nodes_at_distance(&g, 1, 0), HashSet::from([1]));
+        assert_eq!(nodes_at_distance(&g, 1, 1), HashSet::from([2, 5]));
+        assert_eq!(nodes_at_distance(&g, 1, 2), HashSet::from([3, 4]));
+        assert_eq!(nodes_at_distance(&g, 1, 3), HashSet::from([6]));
+        assert_eq!(nodes_at_distance(&g, 1, 4), HashSet::new());
+        assert_eq!(nodes_at_distance(&g, 7, 1), HashSet::new());
+        assert_eq!(nodes_at_distance(&g, 8, 0), HashSet::new());
+    }
+
+    #[test]
+    fn test_ego_graph() {
+        let mut g = simple_graph();
+        g.add_edge(2, 5);
+        let actual = ego_graph(&g, 1, 1);
+        assert_eq!(actual.nodes::<HashSet<_>>(), HashSet::from([1, 2, 5]));
+        assert_eq!(actual.edge_count(), 3);
+        assert!(actual.adj(&2).unwrap().contains(&5));
+
+        let actual = ego_graph(&g, 4, 0);
+        assert_eq!(actual.nodes::<HashSet<_>>(), HashSet::from([4]));
+        assert_eq!(actual.edge_count(), 0);
+    }
+
+    #[test]
+    fn test_ancestors_within() {
+        let mut g: DiGraph<i8> = DiGraph::new();
+        g.add_edges_from(vec![(1, 4), (2, 4), (3, 4), (4, 6), (5, 6), (6, 7), (0, 1)]);
+        assert_eq!(ancestors_within(&g, 7, 0), HashSet::new());
+        assert_eq!(ancestors_within(&g, 7, 1), HashSet::from([6]));
+        assert_eq!(ancestors_within(&g, 7, 2), HashSet::from([4, 5, 6]));
+        assert_eq!(
+            ancestors_within(&g, 7, 3),
+            HashSet::from([1, 2, 3, 4, 5, 6])
+        );
+        assert_eq!(
+            ancestors_within(&g, 7, 10),
+            HashSet::from([0, 1, 2, 3, 4, 5, 6])
+        );
+        assert_eq!(ancestors_within(&g, 0, 3), HashSet::new());
+        assert_eq!(ancestors_within(&g, 9, 3), HashSet::new());
+    }
+
+    #[test]
+    fn test_neighborhood_subgraph() {
+        let mut g = simple_graph();
+        g.add_edges_from(vec![(2, 5), (1, 3), (1, 1)]);
+        let triangles = [(2, 3), (2, 5)].len();
+        let open = neighborhood_subgraph(&g, &1, false);
+        assert_eq!(open.nodes::<HashSet<_>>(), HashSet::from([2, 3, 5]));
+        assert_eq!(open.edge_count(), triangles);
+
+        let closed = neighborhood_subgraph(&g, &1, true);
+        assert_eq!(closed.node_count(), 4);
+        assert_eq!(closed.edge_count(), triangles + 3 + 1);
+        assert_eq!(neighborhood_subgraph(&g, &8, true).node_count(), 0);
+    }
+
+    #[test]
+    fn edges_in_dfs_order() {
+        let mut dg: DiGraph<i8> = DiGraph::new();
+        dg.add_edges_from(vec![(1, 2), (2, 1), (2, 3), (3, 1), (4, 1)]);
+        let edges: Vec<_> = dg.edge_dfs(1).collect();
+        assert_eq!(edges.len(), 4);
+        assert_eq!(edges[0], (1, 2));
+        let expected = HashSet::from([(1, 2), (2, 1), (2, 3), (3, 1)]);
+        assert_eq!(edges.into_iter().collect::<HashSet<_>>(), expected);
+
+        let g = simple_graph();
+        let edges: Vec<_> = g.edge_dfs(6).collect();
+        assert_eq!(edges[0], (6, 4));
+        let unique: HashSet<_> = edges.iter().map(|(u, v)| (*u.min(v), *u.max(v))).collect();
+        assert_eq!(edges.len(), g.edge_count());
+        assert_eq!(unique.len(), g.edge_count());
+        assert_eq!(g.edge_dfs(8).count(), 0);
+    }
+
+    #[test]
+    fn observed_shortest_path() {
+        let g = simple_graph();
+        let mut visited: Vec<(i8, usize)> = Vec::new();
+        let path = BFS::shortest_path_with_observer(&g, 1, 6, |u, d| visited.push((*u, d)));
+        assert_eq!(path, BFS::shortest_path(&g, 1, 6));
+        assert_eq!(visited[0], (1, 0));
+        assert_eq!(visited.last(), Some(&(6, 3)));
+        assert!(visited.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        let mut count = 0;
+        let path = Dijkstra::shortest_path_with_observer(&g, 1, 7, |_, _| count += 1);
+        assert_eq!(path, None);
+        assert_eq!(count, 6);
+    }
+
+    #[test]
+    fn tree_from_parent_map() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (3, 4), (3, 5)]);
+        let parents = BFS::shortest_path_tree(&g, 1);
+        let tree = from_parent_map(&parents, 1).unwrap();
+        assert_eq!(tree.edges_sorted(), vec![(1, 2), (1, 3), (3, 4), (3, 5)]);
+        assert_eq!(tree.in_degree(&1), 0);
+
+        let single = from_parent_map(&HashMap::new(), 1).unwrap();
+        assert_eq!(single.nodes::<Vec<_>>(), vec![1]);
+
+        let cyclic = HashMap::from([(2, 1), (3, 4), (4, 3)]);
+        assert_eq!(from_parent_map(&cyclic, 1), Err(ParentMapError::Cycle));
+        let root_with_parent = HashMap::from([(2, 1), (1, 3)]);
+        assert_eq!(
+            from_parent_map(&root_with_parent, 1),
+            Err(ParentMapError::RootHasParent)
+        );
+        let forest = HashMap::from([(2, 1), (4, 3)]);
+        assert_eq!(from_parent_map(&forest, 1), Err(ParentMapError::Unrooted));
+    }
+
+    #[test]
+    fn test_bfs_predecessors() {
+        let g = simple_graph();
+        let actual = bfs_predecessors(&g, 2);
+        let expected = HashMap::from([
+            (2, None),
+            (1, Some(2)),
+            (3, Some(2)),
+            (4, Some(3)),
+            (5, Some(1)),
+            (6, Some(4)),
+        ]);
+        assert_eq!(actual, expected);
+        assert_eq!(bfs_predecessors(&g, 7), HashMap::from([(7, None)]));
+        assert!(bfs_predecessors(&g, 8).is_empty());
+    }
+
+    #[test]
+    fn closest_of_many_sources() {
+        let g = simple_graph();
+        assert_eq!(
+            multi_source_shortest_path(&g, &[1, 3], 6),
+            Some((2, vec![3, 4, 6]))
+        );
+        assert_eq!(
+            multi_source_shortest_path(&g, &[6, 2], 3),
+            Some((1, vec![2, 3]))
+        );
+        assert_eq!(
+            multi_source_shortest_path(&g, &[9, 5], 5),
+            Some((0, vec![5]))
+        );
+        assert_eq!(multi_source_shortest_path(&g, &[1, 2], 7), None);
+        assert_eq!(multi_source_shortest_path(&g, &[], 1), None);
+    }
+
+    #[test]
+    fn traversal_trees() {
+        let mut g = simple_graph();
+        g.add_edges_from(vec![(2, 5), (3, 6)]);
+        for tree in [bfs_tree(&g, 1), dfs_tree(&g, 1)] {
+            assert_eq!(tree.node_count(), 6);
+            assert_eq!(tree.edge_count(), 5);
+            assert!(crate::sort::is_directed_acyclic_graph(&tree));
+            assert!(tree
+                .iter()
+                .all(|u| tree.in_degree(u) == usize::from(*u != 1)));
+            assert!(tree.edges::<Vec<_>>().iter().all(|(u, v)| g.has_edge(u, v)));
+        }
+        assert_eq!(bfs_tree(&g, 7).node_count(), 1);
+        assert_eq!(dfs_tree(&g, 8).node_count(), 0);
+    }
+
+    #[test]
+    fn seeded_shortest_path() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (1, 3), (2, 4), (3, 4), (4, 5)]);
+        g.add_node(6);
+
+        let paths = (0..32)
+            .map(|seed| shortest_path_seeded(&g, 1, 5, seed).unwrap())
+            .collect::<HashSet<_>>();
+        let expected = HashSet::from([vec![1, 2, 4, 5], vec![1, 3, 4, 5]]);
+        assert_eq!(paths, expected);
+        assert_eq!(
+            shortest_path_seeded(&g, 1, 5, 42),
+            shortest_path_seeded(&g, 1, 5, 42)
+        );
+        assert_eq!(shortest_path_seeded(&g, 1, 6, 42), None);
+        assert_eq!(shortest_path_seeded(&g, 1, 1, 42), Some(vec![1]));
+    }
+
     #[test]
     fn dijkstra_shortest_path_exists() {
         let g = simple_graph();
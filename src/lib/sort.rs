@@ -1,6 +1,6 @@
 //! Graph sorting utilities.
 use crate::graph::{Directed, Graph};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
@@ -26,6 +26,31 @@ where
     topological_generations(g).is_err()
 }
 
+/// Checks if adding an edge `u->v` to a directed graph would create a cycle,
+/// i.e. if `u` is reachable from `v`. The graph is not modified.
+pub fn would_create_cycle<T>(g: &Graph<T, Directed>, u: &T, v: &T) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    if u == v {
+        return true;
+    }
+    let mut visited: HashSet<&T> = HashSet::from([v]);
+    let mut stack: Vec<&T> = vec![v];
+
+    while let Some(node) = stack.pop() {
+        for neighbor in g.adj(node).into_iter().flatten() {
+            if neighbor == u {
+                return true;
+            }
+            if visited.insert(neighbor) {
+                stack.push(neighbor);
+            }
+        }
+    }
+    false
+}
+
 /// Returns `true` if a graph is a directed acyclic graph (DAG) or `false` otherwise.
 pub fn is_directed_acyclic_graph<T>(g: &Graph<T, Directed>) -> bool
 where
@@ -47,7 +72,7 @@ where
         match v {
             0 => zero_indegree.push(k.clone()),
             _ => {
-                indegree_map.insert(k.clone(), v.clone());
+                indegree_map.insert(k.clone(), *v);
             }
         };
     }
@@ -59,7 +84,7 @@ where
         for node in this_generation.iter() {
             for child in g.adj(node).expect("No such node in a graph") {
                 indegree_map.entry(child.clone()).and_modify(|n| *n -= 1);
-                if *indegree_map.get(&child).unwrap() == 0 {
+                if *indegree_map.get(child).unwrap() == 0 {
                     zero_indegree.push(child.clone());
                     indegree_map.remove(child);
                 }
@@ -75,6 +100,21 @@ where
     Ok(generations)
 }
 
+/// Stratifies a DAG into generations with nodes sorted ascending within each generation.
+pub fn topological_generations_sorted<T>(g: &Graph<T, Directed>) -> Result<Vec<Vec<T>>>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+{
+    topological_generations(g).map(|gens| {
+        gens.into_iter()
+            .map(|mut gen| {
+                gen.sort();
+                gen
+            })
+            .collect()
+    })
+}
+
 /// Returns a vector of nodes in a topologically sorted order.
 pub fn topological_sort<T>(g: &Graph<T, Directed>) -> Result<Vec<T>>
 where
@@ -89,6 +129,260 @@ where
     }
 }
 
+/// Lazy Kahn's algorithm state behind [`topological_sort_iter`].
+struct TopologicalSortIter<'a, T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    g: &'a Graph<T, Directed>,
+    indegree: HashMap<&'a T, usize>,
+    ready: VecDeque<&'a T>,
+    done: bool,
+}
+
+impl<T> Iterator for TopologicalSortIter<'_, T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let Some(node) = self.ready.pop_front() else {
+            self.done = true;
+            return (!self.indegree.is_empty()).then_some(Err(CycleError));
+        };
+        for child in self.g.adj(node).expect("No such node in a graph") {
+            let n = self.indegree.get_mut(child).unwrap();
+            *n -= 1;
+            if *n == 0 {
+                self.indegree.remove(child);
+                self.ready.push_back(child);
+            }
+        }
+        Some(Ok(node.clone()))
+    }
+}
+
+/// Returns an iterator lazily yielding nodes in a topologically sorted order.
+///
+/// If a cycle blocks progress, the last item is a `CycleError`.
+pub fn topological_sort_iter<T>(g: &Graph<T, Directed>) -> impl Iterator<Item = Result<T>> + '_
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let mut indegree: HashMap<&T, usize> = HashMap::new();
+    let mut ready: VecDeque<&T> = VecDeque::new();
+    for node in g.iter() {
+        match g.in_degree(node) {
+            0 => ready.push_back(node),
+            n => {
+                indegree.insert(node, n);
+            }
+        }
+    }
+    TopologicalSortIter {
+        g,
+        indegree,
+        ready,
+        done: false,
+    }
+}
+
+/// Returns all topologically sorted orders of nodes in a DAG.
+///
+/// Backtracks over every choice of a node without remaining predecessors, so the
+/// number of orders, and the running time, can grow factorially with the number of nodes.
+pub fn all_topological_sorts<T>(g: &Graph<T, Directed>) -> Result<Vec<Vec<T>>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    if has_cycle(g) {
+        return Err(CycleError);
+    }
+    let mut indegree: HashMap<&T, usize> = g.iter().map(|u| (u, g.in_degree(u))).collect();
+    let mut order: Vec<&T> = Vec::new();
+    let mut orders: Vec<Vec<T>> = Vec::new();
+    extend_topological_sorts(g, &mut indegree, &mut order, &mut orders);
+    Ok(orders)
+}
+
+fn extend_topological_sorts<'a, T>(
+    g: &'a Graph<T, Directed>,
+    indegree: &mut HashMap<&'a T, usize>,
+    order: &mut Vec<&'a T>,
+    orders: &mut Vec<Vec<T>>,
+) where
+    T: Clone + Hash + Eq + Debug,
+{
+    if order.len() == g.node_count() {
+        orders.push(order.iter().map(|u| (*u).clone()).collect());
+        return;
+    }
+    let ready: Vec<&T> = g
+        .iter()
+        .filter(|u| indegree[*u] == 0 && !order.contains(u))
+        .collect();
+    for u in ready {
+        g.adj(u)
+            .unwrap()
+            .iter()
+            .for_each(|v| *indegree.get_mut(v).unwrap() -= 1);
+        order.push(u);
+        extend_topological_sorts(g, indegree, order, orders);
+        order.pop();
+        g.adj(u)
+            .unwrap()
+            .iter()
+            .for_each(|v| *indegree.get_mut(v).unwrap() += 1);
+    }
+}
+
+/// Returns the longest path in a DAG, counting edges.
+pub fn dag_longest_path<T>(g: &Graph<T, Directed>) -> Result<Vec<T>>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    let order = topological_sort(g)?;
+    let mut length: HashMap<&T, usize> = HashMap::new();
+    let mut previous: HashMap<&T, &T> = HashMap::new();
+
+    for node in order.iter() {
+        let best = g
+            .pred(node)
+            .into_iter()
+            .flatten()
+            .max_by_key(|u| length[*u]);
+        if let Some(u) = best {
+            length.insert(node, length[u] + 1);
+            previous.insert(node, u);
+        } else {
+            length.insert(node, 0);
+        }
+    }
+
+    let mut current = match length.iter().max_by_key(|(_, len)| **len) {
+        Some((node, _)) => *node,
+        None => return Ok(vec![]),
+    };
+    let mut path: Vec<T> = vec![current.clone()];
+    while let Some(u) = previous.get(current) {
+        path.push((*u).clone());
+        current = u;
+    }
+    path.reverse();
+    Ok(path)
+}
+
+/// A DAG that maintains a topological order of its nodes as edges are added.
+///
+/// Inserting an edge that contradicts the order only reorders nodes between its endpoints
+/// (Pearce–Kelly), instead of sorting the whole graph again.
+#[derive(Debug, Clone)]
+pub struct OrderedDag<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    graph: Graph<T, Directed>,
+    order: Vec<T>,
+    position: HashMap<T, usize>,
+}
+
+impl<T> Default for OrderedDag<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> OrderedDag<T>
+where
+    T: Clone + Hash + Eq + Debug,
+{
+    /// Create an empty DAG.
+    pub fn new() -> Self {
+        OrderedDag {
+            graph: Graph::new(),
+            order: Vec::new(),
+            position: HashMap::new(),
+        }
+    }
+
+    /// Get the underlying graph.
+    pub fn graph(&self) -> &Graph<T, Directed> {
+        &self.graph
+    }
+
+    /// Get all nodes in a topologically sorted order.
+    pub fn order(&self) -> &[T] {
+        &self.order
+    }
+
+    /// Add a node at the end of the order. Do nothing if it already exists.
+    pub fn add_node(&mut self, u: T) {
+        if !self.position.contains_key(&u) {
+            self.position.insert(u.clone(), self.order.len());
+            self.order.push(u.clone());
+            self.graph.add_node(u);
+        }
+    }
+
+    /// Adds a directed edge u->v, reordering nodes if needed.
+    /// Returns a `CycleError` and leaves the DAG unchanged if the edge would create a cycle.
+    pub fn add_edge(&mut self, u: T, v: T) -> Result<()> {
+        if self.graph.has_edge(&u, &v) {
+            return Ok(());
+        }
+        if would_create_cycle(&self.graph, &u, &v) {
+            return Err(CycleError);
+        }
+        self.add_node(u.clone());
+        self.add_node(v.clone());
+        let (lower, upper) = (self.position[&v], self.position[&u]);
+        if lower < upper {
+            let forward = self.affected(&v, |p| p <= upper, |g, x| g.adj(x));
+            let backward = self.affected(&u, |p| p >= lower, |g, x| g.pred(x));
+            let mut slots: Vec<usize> = forward
+                .iter()
+                .chain(backward.iter())
+                .map(|x| self.position[x])
+                .collect();
+            slots.sort_unstable();
+            for (x, slot) in backward.into_iter().chain(forward).zip(slots) {
+                self.position.insert(x.clone(), slot);
+                self.order[slot] = x;
+            }
+        }
+        self.graph.add_edge(u, v);
+        Ok(())
+    }
+
+    /// Returns nodes reachable from `start` through nodes whose position satisfies `within`,
+    /// sorted by their current position.
+    fn affected<P, N>(&self, start: &T, within: P, next: N) -> Vec<T>
+    where
+        P: Fn(usize) -> bool,
+        N: for<'a> Fn(&'a Graph<T, Directed>, &T) -> Option<&'a HashSet<T>>,
+    {
+        let mut visited: HashSet<&T> = HashSet::from([start]);
+        let mut stack: Vec<&T> = vec![start];
+        while let Some(node) = stack.pop() {
+            for neighbor in next(&self.graph, node).into_iter().flatten() {
+                if within(self.position[neighbor]) && visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        let mut nodes: Vec<T> = visited.into_iter().cloned().collect();
+        nodes.sort_by_key(|x| self.position[x]);
+        nodes
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::topological_generations;
@@ -118,17 +412,67 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_topological_generations_sorted() {
+        let g = simple_graph();
+        let actual = topological_generations_sorted(&g).ok().unwrap();
+        let expected = vec![vec![1, 7], vec![2, 5], vec![3], vec![4], vec![6]];
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_topological_sort() {
         let g = simple_graph();
         let actual = topological_sort(&g).ok().unwrap();
-        let expected = vec![
+        let expected = [
+            vec![1, 7, 2, 5, 3, 4, 6],
+            vec![7, 1, 2, 5, 3, 4, 6],
+            vec![1, 7, 5, 2, 3, 4, 6],
+            vec![7, 1, 5, 2, 3, 4, 6],
+        ];
+        assert!(expected.contains(&actual));
+    }
+
+    #[test]
+    fn lazy_topological_sort() {
+        let g = simple_graph();
+        let actual: Vec<i8> = topological_sort_iter(&g).collect::<Result<_>>().unwrap();
+        let expected = [
             vec![1, 7, 2, 5, 3, 4, 6],
             vec![7, 1, 2, 5, 3, 4, 6],
             vec![1, 7, 5, 2, 3, 4, 6],
             vec![7, 1, 5, 2, 3, 4, 6],
         ];
         assert!(expected.contains(&actual));
+        assert_eq!(actual.len(), topological_sort(&g).unwrap().len());
+
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(0, 1), (1, 2), (2, 3), (3, 1)]);
+        let mut iter = topological_sort_iter(&g);
+        assert_eq!(iter.next().unwrap().unwrap(), 0);
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_all_topological_sorts() {
+        let g = simple_graph();
+        let actual: HashSet<Vec<i8>> = all_topological_sorts(&g).unwrap().into_iter().collect();
+        let expected = HashSet::from([
+            vec![1, 7, 2, 5, 3, 4, 6],
+            vec![7, 1, 2, 5, 3, 4, 6],
+            vec![1, 7, 5, 2, 3, 4, 6],
+            vec![7, 1, 5, 2, 3, 4, 6],
+        ]);
+        // Beyond the generation-by-generation orders, the isolated node 7 may take any of
+        // 7 positions among the 3 orders of the rest (5 can also come after 3).
+        assert!(expected.is_subset(&actual));
+        assert!(actual.contains(&vec![1, 2, 3, 5, 4, 6, 7]));
+        assert_eq!(actual.len(), 21);
+
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 1)]);
+        assert!(all_topological_sorts(&g).is_err());
     }
 
     #[test]
@@ -151,6 +495,43 @@ mod tests {
         assert!(!has_cycle(&g));
     }
 
+    #[test]
+    fn back_edge_would_create_cycle() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4)]);
+        assert!(would_create_cycle(&g, &4, &1));
+        assert!(would_create_cycle(&g, &3, &2));
+        assert!(would_create_cycle(&g, &2, &2));
+    }
+
+    #[test]
+    fn forward_edge_would_not_create_cycle() {
+        let g = simple_graph();
+        assert!(!would_create_cycle(&g, &1, &4));
+        assert!(!would_create_cycle(&g, &2, &5));
+        assert!(!would_create_cycle(&g, &7, &1));
+        assert!(!would_create_cycle(&g, &1, &8));
+    }
+
+    #[test]
+    fn longest_path_in_diamond() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (1, 4)]);
+        assert_eq!(dag_longest_path(&g).unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(
+            dag_longest_path(&simple_graph()).unwrap(),
+            vec![1, 2, 3, 4, 6]
+        );
+    }
+
+    #[test]
+    fn longest_path_errors() {
+        let mut g: Graph<i8, Directed> = Graph::new();
+        assert_eq!(dag_longest_path(&g).unwrap(), vec![]);
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1)]);
+        assert!(dag_longest_path(&g).is_err());
+    }
+
     #[test]
     fn detect_no_dag() {
         let mut g: Graph<i8, Directed> = Graph::new();
@@ -163,4 +544,54 @@ mod tests {
         let g = simple_graph();
         assert!(is_directed_acyclic_graph(&g));
     }
+
+    #[test]
+    fn ordered_dag_keeps_order_valid() {
+        let is_valid = |dag: &OrderedDag<i8>| {
+            let position: HashMap<&i8, usize> = dag
+                .order()
+                .iter()
+                .enumerate()
+                .map(|(i, u)| (u, i))
+                .collect();
+            position.len() == dag.graph().node_count()
+                && dag
+                    .graph()
+                    .edges::<Vec<_>>()
+                    .iter()
+                    .all(|(u, v)| position[u] < position[v])
+        };
+        let mut dag: OrderedDag<i8> = OrderedDag::new();
+        for u in 1..=7 {
+            dag.add_node(u);
+        }
+        let edges = [
+            (6, 5),
+            (5, 4),
+            (7, 1),
+            (4, 3),
+            (2, 6),
+            (3, 1),
+            (1, 2),
+            (6, 4),
+            (5, 3),
+        ];
+        for (i, (u, v)) in edges.iter().enumerate() {
+            let result = dag.add_edge(*u, *v);
+            assert_eq!(result.is_ok(), i != 6, "edge {u}->{v}");
+            assert!(is_valid(&dag), "after {u}->{v}: {:?}", dag.order());
+        }
+        assert_eq!(dag.graph().edge_count(), 8);
+        assert!(!dag.graph().has_edge(&1, &2));
+
+        dag.add_edge(8, 7).unwrap();
+        dag.add_edge(1, 9).unwrap();
+        assert!(is_valid(&dag));
+        assert_eq!(dag.order().len(), 9);
+        assert_eq!(dag.order().last(), Some(&9));
+        assert_eq!(
+            dag.add_edge(9, 8).unwrap_err().to_string(),
+            CycleError.to_string()
+        );
+    }
 }
@@ -0,0 +1,137 @@
+//! Read-only views of a graph.
+use crate::graph::{Graph, GraphType};
+use crate::search::build_path;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A read-only view of a graph which hides nodes failing a predicate, together with their edges.
+pub struct FilteredGraph<'a, T, G, F>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    F: Fn(&T) -> bool,
+{
+    graph: &'a Graph<T, G>,
+    keep: F,
+}
+
+impl<T, G> Graph<T, G>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+{
+    /// Returns a view of a graph with only nodes for which `keep` returns `true`.
+    /// Nothing is copied.
+    pub fn filtered<F>(&self, keep: F) -> FilteredGraph<'_, T, G, F>
+    where
+        F: Fn(&T) -> bool,
+    {
+        FilteredGraph { graph: self, keep }
+    }
+}
+
+impl<'a, T, G, F> FilteredGraph<'a, T, G, F>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    F: Fn(&T) -> bool,
+{
+    /// Returns `true` if a node is visible in the view.
+    pub fn contains(&self, u: &T) -> bool {
+        self.graph.adj(u).is_some() && (self.keep)(u)
+    }
+
+    /// Iterate over visible nodes.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.graph.iter().filter(|u| (self.keep)(u))
+    }
+
+    /// Get all visible nodes.
+    pub fn nodes<B: FromIterator<T>>(&self) -> B {
+        self.iter().cloned().collect()
+    }
+
+    /// Iterate over visible neighbors of a visible node.
+    pub fn neighbors(&self, u: &T) -> impl Iterator<Item = &'a T> + '_ {
+        let adj = if self.contains(u) {
+            self.graph.adj(u)
+        } else {
+            None
+        };
+        adj.into_iter().flatten().filter(|v| (self.keep)(v))
+    }
+
+    /// Searches the view with BFS, returning predecessors of discovered nodes
+    /// and whether `target` was reached.
+    fn bfs(&self, source: &T, target: &T) -> (bool, HashMap<T, T>) {
+        let mut previous: HashMap<T, T> = HashMap::new();
+        if !self.contains(source) {
+            return (false, previous);
+        }
+        let mut visited: HashSet<&T> = HashSet::from([source]);
+        let mut queue: VecDeque<&T> = VecDeque::from([source]);
+
+        while let Some(node) = queue.pop_front() {
+            if node == target {
+                return (true, previous);
+            }
+            for neighbor in self.neighbors(node) {
+                if visited.insert(neighbor) {
+                    previous.insert(neighbor.clone(), node.clone());
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        (false, previous)
+    }
+
+    /// A shortest path between a `source` and a `target` nodes within the view.
+    pub fn shortest_path(&self, source: T, target: T) -> Option<Vec<T>> {
+        let (found, mut previous) = self.bfs(&source, &target);
+        found.then(|| build_path(&mut previous, source, target))
+    }
+
+    /// Returns `true` if the view has a path from `source` to `target`.
+    pub fn has_path(&self, source: T, target: T) -> bool {
+        self.bfs(&source, &target).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::{SearchAlgorithm, BFS};
+
+    fn simple_graph() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6)]);
+        g.add_node(7);
+        g
+    }
+
+    #[test]
+    fn filtered_bridge_blocks_path() {
+        let g = simple_graph();
+        assert!(BFS::has_path(&g, 1, 6));
+        let view = g.filtered(|u| *u != 4);
+        assert!(!view.has_path(1, 6));
+        assert!(view.has_path(1, 3));
+        assert_eq!(view.shortest_path(3, 5), Some(vec![3, 2, 1, 5]));
+        assert_eq!(view.shortest_path(1, 4), None);
+    }
+
+    #[test]
+    fn filtered_nodes_and_neighbors() {
+        let g = simple_graph();
+        let view = g.filtered(|u| u % 2 == 1);
+        assert_eq!(view.nodes::<HashSet<_>>(), HashSet::from([1, 3, 5, 7]));
+        assert_eq!(
+            view.neighbors(&1).collect::<HashSet<_>>(),
+            HashSet::from([&5])
+        );
+        assert_eq!(view.neighbors(&4).count(), 0);
+        assert!(!view.contains(&2));
+        assert_eq!(g.node_count(), 7);
+    }
+}
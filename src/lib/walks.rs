@@ -0,0 +1,246 @@
+//! Random walks on a graph.
+use crate::components::connected_components;
+use crate::graph::{Graph, GraphType, Undirected};
+use crate::random::Rng;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Neighbors of `u` in ascending order, so that random choices do not depend on hashing.
+fn sorted_neighbors<'a, T, G>(g: &'a Graph<T, G>, u: &T) -> Vec<&'a T>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+    G: GraphType,
+{
+    let mut neighbors: Vec<&T> = g.adj(u).into_iter().flatten().collect();
+    neighbors.sort();
+    neighbors
+}
+
+/// Performs a random walk of `steps` steps from `start`, moving to a uniformly
+/// chosen neighbor at every step. The walk is reproducible for a given `seed`.
+///
+/// Returns visited nodes including `start`, i.e. `steps + 1` nodes unless the
+/// walk reaches a node without neighbors earlier.
+pub fn random_walk<T, G>(g: &Graph<T, G>, start: T, steps: usize, seed: u64) -> Vec<T>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+    G: GraphType,
+{
+    let mut rng = Rng::new(seed);
+    let mut walk: Vec<T> = vec![start];
+
+    for _ in 0..steps {
+        let neighbors = sorted_neighbors(g, walk.last().unwrap());
+        if neighbors.is_empty() {
+            break;
+        }
+        walk.push(neighbors[rng.gen_index(neighbors.len())].clone());
+    }
+    walk
+}
+
+/// Performs a node2vec-style biased random walk of `steps` steps from `start`.
+/// The walk is reproducible for a given `seed`.
+///
+/// Having moved from `t` to `v`, the next node `x` is chosen among neighbors
+/// of `v` with an unnormalized probability of `1 / p` if `x` is `t`, `1` if
+/// `x` is adjacent to `t` and `1 / q` otherwise. A low return parameter `p`
+/// keeps the walk local, a low in-out parameter `q` pushes it outwards. Uses
+/// rejection sampling, so with `p = q = 1` it yields the same walk as
+/// [`random_walk`] for the same seed.
+///
+/// # Panics
+///
+/// Panics unless `p` and `q` are positive and finite, as otherwise no
+/// candidate may ever be accepted.
+pub fn biased_random_walk<T, G>(
+    g: &Graph<T, G>,
+    start: T,
+    steps: usize,
+    p: f64,
+    q: f64,
+    seed: u64,
+) -> Vec<T>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+    G: GraphType,
+{
+    assert!(
+        p > 0.0 && q > 0.0 && p.is_finite() && q.is_finite(),
+        "p and q must be positive and finite"
+    );
+    let mut rng = Rng::new(seed);
+    let mut walk: Vec<T> = vec![start];
+    let max_bias = (1.0 / p).max(1.0).max(1.0 / q);
+
+    for _ in 0..steps {
+        let current = walk.last().unwrap();
+        let neighbors = sorted_neighbors(g, current);
+        if neighbors.is_empty() {
+            break;
+        }
+        let previous = walk.len().checked_sub(2).map(|i| &walk[i]);
+        let next = loop {
+            let candidate = neighbors[rng.gen_index(neighbors.len())];
+            let bias = match previous {
+                None => break candidate,
+                Some(t) if t == candidate => 1.0 / p,
+                Some(t) if g.has_edge(t, candidate) => 1.0,
+                Some(_) => 1.0 / q,
+            };
+            if bias >= max_bias || rng.next_f64() * max_bias < bias {
+                break candidate;
+            }
+        };
+        walk.push(next.clone());
+    }
+    walk
+}
+
+/// Returns edges of a uniformly random spanning tree of a connected graph, using Wilson's
+/// algorithm. The tree is reproducible for a given `seed`.
+///
+/// Every node walks randomly until it hits the tree built so far, and the walk with its loops
+/// erased joins the tree. For a disconnected graph a random spanning forest is returned.
+pub fn random_spanning_tree<T>(g: &Graph<T, Undirected>, seed: u64) -> Vec<(T, T)>
+where
+    T: Clone + Hash + Eq + Debug + Ord,
+{
+    let mut rng = Rng::new(seed);
+    let mut in_tree: HashSet<T> = connected_components(g)
+        .into_iter()
+        .map(|c| c.into_iter().min().unwrap())
+        .collect();
+    let mut next: HashMap<T, T> = HashMap::new();
+    let mut nodes: Vec<&T> = g.iter().collect();
+    nodes.sort();
+
+    let mut tree: Vec<(T, T)> = Vec::new();
+    for u in nodes {
+        let mut x = u;
+        while !in_tree.contains(x) {
+            let neighbors = sorted_neighbors(g, x);
+            let chosen = neighbors[rng.gen_index(neighbors.len())];
+            next.insert(x.clone(), chosen.clone());
+            x = chosen;
+        }
+        let mut x = u.clone();
+        while !in_tree.contains(&x) {
+            let parent = next[&x].clone();
+            in_tree.insert(x.clone());
+            tree.push((x, parent.clone()));
+            x = parent;
+        }
+    }
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DiGraph;
+
+    fn simple_graph() -> Graph<i8> {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (4, 6)]);
+        g.add_node(7);
+        g
+    }
+
+    #[test]
+    fn walk_follows_edges() {
+        let g = simple_graph();
+        let walk = random_walk(&g, 1, 20, 7);
+        assert_eq!(walk.len(), 21);
+        assert_eq!(walk[0], 1);
+        assert!(walk.windows(2).all(|w| g.has_edge(&w[0], &w[1])));
+    }
+
+    #[test]
+    fn walk_is_reproducible() {
+        let g = simple_graph();
+        assert_eq!(random_walk(&g, 1, 20, 7), random_walk(&g, 1, 20, 7));
+        assert_ne!(random_walk(&g, 1, 20, 7), random_walk(&g, 1, 20, 8));
+    }
+
+    #[test]
+    fn unbiased_walk_is_plain_walk() {
+        let g = simple_graph();
+        for seed in 0..10 {
+            assert_eq!(
+                biased_random_walk(&g, 1, 30, 1.0, 1.0, seed),
+                random_walk(&g, 1, 30, seed)
+            );
+        }
+    }
+
+    #[test]
+    fn biased_walk_changes_distribution() {
+        let g = simple_graph();
+        let returns = |walk: Vec<i8>| walk.windows(3).filter(|w| w[0] == w[2]).count();
+
+        let local = biased_random_walk(&g, 1, 500, 0.1, 1.0, 3);
+        let outward = biased_random_walk(&g, 1, 500, 10.0, 1.0, 3);
+        assert_eq!(local, biased_random_walk(&g, 1, 500, 0.1, 1.0, 3));
+        assert!(local.windows(2).all(|w| g.has_edge(&w[0], &w[1])));
+        assert!(returns(local) > returns(outward));
+    }
+
+    #[test]
+    fn biased_walk_rejects_invalid_parameters() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 1)]);
+        for (p, q) in [
+            (1.0, 0.0),
+            (0.0, 1.0),
+            (-1.0, 1.0),
+            (1.0, f64::INFINITY),
+            (f64::NAN, 1.0),
+        ] {
+            let walk = std::panic::catch_unwind(|| biased_random_walk(&g, 1, 10, p, q, 0));
+            assert!(walk.is_err(), "p = {p}, q = {q}");
+        }
+    }
+
+    #[test]
+    fn walk_stops_at_dead_end() {
+        let mut g: DiGraph<i8> = DiGraph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3)]);
+        assert_eq!(random_walk(&g, 1, 10, 0), vec![1, 2, 3]);
+        assert_eq!(random_walk(&simple_graph(), 7, 10, 0), vec![7]);
+    }
+
+    #[test]
+    fn wilson_spanning_tree() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1), (1, 3), (4, 5), (5, 5)]);
+        for seed in 0..20 {
+            let tree = random_spanning_tree(&g, seed);
+            assert_eq!(tree.len(), g.node_count() - 1);
+            assert!(tree.iter().all(|(u, v)| g.has_edge(u, v)));
+            let mut t: Graph<i8> = Graph::new();
+            t.add_edges_from(tree.clone());
+            assert_eq!(crate::components::number_connected_components(&t), 1);
+            assert_eq!(t.node_count(), g.node_count());
+            assert_eq!(tree, random_spanning_tree(&g, seed));
+        }
+    }
+
+    #[test]
+    fn wilson_is_uniform_on_cycle() {
+        let mut g: Graph<i8> = Graph::new();
+        g.add_edges_from(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+        let mut counts: HashMap<Vec<(i8, i8)>, usize> = HashMap::new();
+        for seed in 0..400 {
+            let mut tree: Vec<_> = random_spanning_tree(&g, seed)
+                .into_iter()
+                .map(|(u, v)| (u.min(v), u.max(v)))
+                .collect();
+            tree.sort();
+            *counts.entry(tree).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 4);
+        assert!(counts.values().all(|c| *c > 60));
+    }
+}
@@ -0,0 +1,332 @@
+//! Definition of weighted graphs.
+use crate::graph::{Directed, Graph, GraphType, Undirected};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+/// A type that can be used as an edge weight.
+pub trait Weight: Copy + Debug + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// Converts the weight to `f64`, possibly losing precision.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_weight {
+    ($zero:expr => $($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_weight!(0 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_weight!(0.0 => f32, f64);
+
+/// A graph object with a weight attached to every edge.
+#[derive(Debug, Clone)]
+pub struct WeightedGraph<T, G = Undirected, W = f64>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    graph: Graph<T, G>,
+    weights: HashMap<(T, T), W>,
+}
+
+impl<T, G, W> Default for WeightedGraph<T, G, W>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, G, W> WeightedGraph<T, G, W>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    /// Create an empty weighted graph.
+    pub fn new() -> Self {
+        WeightedGraph {
+            graph: Graph::new(),
+            weights: HashMap::new(),
+        }
+    }
+
+    /// Get the underlying unweighted graph.
+    pub fn graph(&self) -> &Graph<T, G> {
+        &self.graph
+    }
+
+    /// Iterate over a graph, i.e. over its keys.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.graph.iter()
+    }
+
+    /// Get all nodes from a graph.
+    pub fn nodes<B: FromIterator<T>>(&self) -> B {
+        self.graph.nodes()
+    }
+
+    /// Get adjacent elements in a graph.
+    pub fn adj(&self, u: &T) -> Option<&HashSet<T>> {
+        self.graph.adj(u)
+    }
+
+    /// Get the weight of an edge (u->v).
+    pub fn weight(&self, u: &T, v: &T) -> Option<W> {
+        self.weights.get(&(u.clone(), v.clone())).copied()
+    }
+
+    /// Get all edges with their weights, with every undirected edge listed once as `(u, v, w)`
+    /// with `u <= v`.
+    pub fn weighted_edges<B: FromIterator<(T, T, W)>>(&self) -> B
+    where
+        T: Ord,
+    {
+        self.weights
+            .iter()
+            .filter(|((u, v), _)| G::is_directed() || u <= v)
+            .map(|((u, v), w)| (u.clone(), v.clone(), *w))
+            .collect()
+    }
+}
+
+impl<T, W> WeightedGraph<T, Undirected, W>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    /// Add a node. Do nothing if it already exists.
+    pub fn add_node(&mut self, u: T) {
+        self.graph.add_node(u);
+    }
+
+    /// Adds a weighted edge in a graph (u<->v), overwriting the weight if it already exists.
+    pub fn add_edge(&mut self, u: T, v: T, weight: W) {
+        self.weights.insert((u.clone(), v.clone()), weight);
+        self.weights.insert((v.clone(), u.clone()), weight);
+        self.graph.add_edge(u, v);
+    }
+
+    /// Add many weighted edges at once. Weights of existing edges are overwritten.
+    pub fn add_weighted_edges_from<I: IntoIterator<Item = (T, T, W)>>(&mut self, edges: I) {
+        for (u, v, weight) in edges.into_iter() {
+            self.add_edge(u, v, weight);
+        }
+    }
+}
+
+impl<T, W> WeightedGraph<T, Directed, W>
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    /// Add a node. Do nothing if it already exists.
+    pub fn add_node(&mut self, u: T) {
+        self.graph.add_node(u);
+    }
+
+    /// Adds a weighted edge in a graph (u->v), overwriting the weight if it already exists.
+    pub fn add_edge(&mut self, u: T, v: T, weight: W) {
+        self.weights.insert((u.clone(), v.clone()), weight);
+        self.graph.add_edge(u, v);
+    }
+
+    /// Add many weighted edges at once. Weights of existing edges are overwritten.
+    pub fn add_weighted_edges_from<I: IntoIterator<Item = (T, T, W)>>(&mut self, edges: I) {
+        for (u, v, weight) in edges.into_iter() {
+            self.add_edge(u, v, weight);
+        }
+    }
+
+    /// Returns an undirected weighted graph with an edge u<->v for every edge u->v.
+    /// Weights of reciprocal edges u->v and v->u are merged with `combine`, in no particular order.
+    pub fn to_undirected_with<F>(&self, combine: F) -> WeightedGraph<T, Undirected, W>
+    where
+        F: Fn(W, W) -> W,
+    {
+        let mut g: WeightedGraph<T, Undirected, W> = WeightedGraph::new();
+        for u in self.iter() {
+            g.add_node(u.clone());
+        }
+        for ((u, v), weight) in self.weights.iter() {
+            if g.weight(u, v).is_some() {
+                continue;
+            }
+            let weight = match self.weight(v, u) {
+                Some(reverse) if u != v => combine(*weight, reverse),
+                _ => *weight,
+            };
+            g.add_edge(u.clone(), v.clone(), weight);
+        }
+        g
+    }
+}
+
+/// Sums weights of the given edges.
+fn sum_weights<'a, T, G, W>(
+    g: &WeightedGraph<T, G, W>,
+    neighbors: impl Iterator<Item = (&'a T, &'a T)>,
+) -> W
+where
+    T: Clone + Hash + Eq + Debug + 'a,
+    G: GraphType,
+    W: Weight,
+{
+    neighbors.fold(W::zero(), |acc, (u, v)| {
+        acc + g.weight(u, v).expect("No such edge in a graph")
+    })
+}
+
+/// Returns the strength of a node, i.e. the sum of weights of its incident edges,
+/// counting a self-loop twice.
+pub fn strength<T, W>(g: &WeightedGraph<T, Undirected, W>, u: &T) -> W
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    let self_loop = g
+        .adj(u)
+        .filter(|neighbors| neighbors.contains(u))
+        .map(|_| u);
+    let neighbors = g.adj(u).into_iter().flatten().chain(self_loop);
+    sum_weights(g, neighbors.map(|v| (u, v)))
+}
+
+/// Returns the sum of weights of edges going out of a node.
+pub fn out_strength<T, W>(g: &WeightedGraph<T, Directed, W>, u: &T) -> W
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    sum_weights(g, g.adj(u).into_iter().flatten().map(|v| (u, v)))
+}
+
+/// Returns the sum of weights of edges coming into a node.
+pub fn in_strength<T, W>(g: &WeightedGraph<T, Directed, W>, u: &T) -> W
+where
+    T: Clone + Hash + Eq + Debug,
+    W: Weight,
+{
+    sum_weights(g, g.graph().pred(u).into_iter().flatten().map(|v| (v, u)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted_graph() -> WeightedGraph<i8> {
+        let mut g: WeightedGraph<i8> = WeightedGraph::new();
+        g.add_edge(1, 2, 0.5);
+        g.add_edge(1, 3, 1.5);
+        g.add_edge(2, 3, 2.0);
+        g.add_node(4);
+        g
+    }
+
+    #[test]
+    fn edge_weights() {
+        let g = weighted_graph();
+        assert_eq!(g.weight(&1, &2), Some(0.5));
+        assert_eq!(g.weight(&2, &1), Some(0.5));
+        assert_eq!(g.weight(&1, &4), None);
+    }
+
+    #[test]
+    fn add_weighted_edges_from() {
+        let mut g: WeightedGraph<i8> = WeightedGraph::new();
+        g.add_weighted_edges_from(vec![(1, 2, 0.5), (2, 3, 1.5), (1, 2, 2.5)]);
+        assert_eq!(g.weight(&1, &2), Some(2.5));
+        assert_eq!(g.weight(&2, &1), Some(2.5));
+        assert_eq!(g.weight(&3, &2), Some(1.5));
+
+        let mut dg: WeightedGraph<i8, Directed, u32> = WeightedGraph::new();
+        dg.add_weighted_edges_from([(1, 2, 3), (2, 1, 4)]);
+        assert_eq!(dg.weight(&1, &2), Some(3));
+        assert_eq!(dg.weight(&2, &1), Some(4));
+    }
+
+    #[test]
+    fn weighted_edges() {
+        let mut actual: Vec<(i8, i8, f64)> = weighted_graph().weighted_edges();
+        actual.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(actual, vec![(1, 2, 0.5), (1, 3, 1.5), (2, 3, 2.0)]);
+
+        let mut dg: WeightedGraph<i8, Directed, u32> = WeightedGraph::new();
+        dg.add_weighted_edges_from([(2, 1, 4), (1, 2, 3), (3, 3, 1)]);
+        let mut actual: Vec<(i8, i8, u32)> = dg.weighted_edges();
+        actual.sort();
+        assert_eq!(actual, vec![(1, 2, 3), (2, 1, 4), (3, 3, 1)]);
+    }
+
+    #[test]
+    fn undirected_strength() {
+        let g = weighted_graph();
+        assert_eq!(strength(&g, &1), 2.0);
+        assert_eq!(strength(&g, &3), 3.5);
+        assert_eq!(strength(&g, &4), 0.0);
+        assert_eq!(strength(&g, &5), 0.0);
+    }
+
+    #[test]
+    fn self_loop_strength_matches_degree() {
+        let mut g: WeightedGraph<i8, Undirected, u32> = WeightedGraph::new();
+        g.add_weighted_edges_from([(1, 1, 1), (1, 2, 1), (2, 3, 1)]);
+        for u in 1..=3 {
+            assert_eq!(strength(&g, &u) as usize, g.graph().degree(&u));
+        }
+
+        let mut h = weighted_graph();
+        h.add_edge(1, 1, 0.25);
+        assert_eq!(strength(&h, &1), 2.5);
+    }
+
+    #[test]
+    fn directed_strength() {
+        let mut g: WeightedGraph<i8, Directed, u32> = WeightedGraph::new();
+        g.add_edge(1, 2, 3);
+        g.add_edge(1, 3, 4);
+        g.add_edge(3, 2, 5);
+        g.add_node(4);
+        assert_eq!(out_strength(&g, &1), 7);
+        assert_eq!(in_strength(&g, &1), 0);
+        assert_eq!(in_strength(&g, &2), 8);
+        assert_eq!(out_strength(&g, &3), 5);
+        assert_eq!(in_strength(&g, &4), 0);
+        assert_eq!(out_strength(&g, &4), 0);
+    }
+
+    #[test]
+    fn combine_reciprocal_weights() {
+        let mut dg: WeightedGraph<i8, Directed, u32> = WeightedGraph::new();
+        dg.add_weighted_edges_from([(1, 2, 3), (2, 1, 4), (2, 3, 5), (3, 3, 1)]);
+        dg.add_node(4);
+
+        let g = dg.to_undirected_with(u32::min);
+        assert_eq!(g.weight(&1, &2), Some(3));
+        assert_eq!(g.weight(&2, &1), Some(3));
+        assert_eq!(g.weight(&3, &2), Some(5));
+        assert_eq!(g.weight(&3, &3), Some(1));
+        assert_eq!(g.graph().node_count(), 4);
+        assert_eq!(g.graph().edge_count(), 3);
+        assert_eq!(dg.to_undirected_with(|a, b| a + b).weight(&1, &2), Some(7));
+    }
+}
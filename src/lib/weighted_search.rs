@@ -0,0 +1,300 @@
+//! Path searching in a weighted graph.
+use crate::graph::GraphType;
+use crate::search::build_path;
+use crate::weighted::{Weight, WeightedGraph};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A heap entry ordered so that the smallest score is popped first.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct MinScored<W, T>(pub W, pub T);
+
+impl<W: PartialOrd, T> Ord for MinScored<W, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<W: PartialOrd, T> PartialOrd for MinScored<W, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd, T> PartialEq for MinScored<W, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd, T> Eq for MinScored<W, T> {}
+
+/// Computes distances and a predecessor tree from `source` to every reachable
+/// node using Dijkstra's algorithm. Weights must be non-negative.
+pub fn single_source_dijkstra<T, G, W>(
+    g: &WeightedGraph<T, G, W>,
+    source: T,
+) -> (HashMap<T, W>, HashMap<T, T>)
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    dijkstra_util(g, source, None)
+}
+
+/// A shortest weighted path between a `source` and a `target` nodes together with its cost.
+pub fn dijkstra_path<T, G, W>(
+    g: &WeightedGraph<T, G, W>,
+    source: T,
+    target: T,
+) -> Option<(W, Vec<T>)>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    let (dist, mut previous) = dijkstra_util(g, source.clone(), Some(&target));
+    let cost = *dist.get(&target)?;
+    Some((cost, build_path(&mut previous, source, target)))
+}
+
+/// A shortest weighted path between a `source` and a `target` nodes in a graph whose
+/// edges weigh 0 or 1, together with its cost. Any non-zero weight is treated as 1.
+///
+/// Uses a double-ended queue instead of a priority queue: nodes reached over
+/// edges of weight 0 are pushed to the front and the others to the back.
+pub fn zero_one_bfs<T, G, W>(
+    g: &WeightedGraph<T, G, W>,
+    source: T,
+    target: T,
+) -> Option<(usize, Vec<T>)>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    g.adj(&source)?;
+    let mut dist: HashMap<T, usize> = HashMap::from([(source.clone(), 0)]);
+    let mut previous: HashMap<T, T> = HashMap::new();
+    let mut deque: VecDeque<(usize, T)> = VecDeque::from([(0, source.clone())]);
+
+    while let Some((cost, node)) = deque.pop_front() {
+        if node == target {
+            return Some((cost, build_path(&mut previous, source, target)));
+        }
+        if cost > dist[&node] {
+            continue;
+        }
+        for neighbor in g.adj(&node).expect("No such node in a graph") {
+            let zero = g.weight(&node, neighbor).unwrap() == W::zero();
+            let next = if zero { cost } else { cost + 1 };
+            if dist.get(neighbor).is_none_or(|d| next < *d) {
+                dist.insert(neighbor.clone(), next);
+                previous.insert(neighbor.clone(), node.clone());
+                if zero {
+                    deque.push_front((next, neighbor.clone()));
+                } else {
+                    deque.push_back((next, neighbor.clone()));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns `true` if a `target` node can be reached from a `source` node using only edges
+/// that weigh at least `threshold`.
+pub fn has_path_with_min_weight<T, G, W>(
+    g: &WeightedGraph<T, G, W>,
+    source: T,
+    target: T,
+    threshold: W,
+) -> bool
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    if g.adj(&source).is_none() {
+        return false;
+    }
+    let mut visited: HashSet<&T> = HashSet::from([&source]);
+    let mut queue: VecDeque<&T> = VecDeque::from([&source]);
+
+    while let Some(node) = queue.pop_front() {
+        if *node == target {
+            return true;
+        }
+        for neighbor in g.adj(node).unwrap() {
+            if g.weight(node, neighbor).unwrap() >= threshold && visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    false
+}
+
+/// A path between distinct `source` and `target` nodes which maximizes its bottleneck, i.e.
+/// the minimum weight of an edge along it, together with that bottleneck.
+///
+/// Uses a modified Dijkstra's algorithm which settles the widest node first.
+pub fn widest_path<T, G, W>(g: &WeightedGraph<T, G, W>, source: T, target: T) -> Option<(W, Vec<T>)>
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    let mut width: HashMap<T, W> = HashMap::new();
+    let mut previous: HashMap<T, T> = HashMap::new();
+    let mut heap: BinaryHeap<Reverse<MinScored<W, T>>> = BinaryHeap::new();
+
+    for neighbor in g.adj(&source)? {
+        if *neighbor != source {
+            let w = g.weight(&source, neighbor).unwrap();
+            width.insert(neighbor.clone(), w);
+            previous.insert(neighbor.clone(), source.clone());
+            heap.push(Reverse(MinScored(w, neighbor.clone())));
+        }
+    }
+
+    while let Some(Reverse(MinScored(w, node))) = heap.pop() {
+        if node == target {
+            return Some((w, build_path(&mut previous, source, target)));
+        }
+        if w < width[&node] {
+            continue;
+        }
+        for neighbor in g.adj(&node).expect("No such node in a graph") {
+            if *neighbor == source {
+                continue;
+            }
+            let edge = g.weight(&node, neighbor).expect("No such edge in a graph");
+            let next = if edge < w { edge } else { w };
+            if width.get(neighbor).is_none_or(|b| next > *b) {
+                width.insert(neighbor.clone(), next);
+                previous.insert(neighbor.clone(), node.clone());
+                heap.push(Reverse(MinScored(next, neighbor.clone())));
+            }
+        }
+    }
+    None
+}
+
+fn dijkstra_util<T, G, W>(
+    g: &WeightedGraph<T, G, W>,
+    source: T,
+    target: Option<&T>,
+) -> (HashMap<T, W>, HashMap<T, T>)
+where
+    T: Clone + Hash + Eq + Debug,
+    G: GraphType,
+    W: Weight,
+{
+    let mut dist: HashMap<T, W> = HashMap::new();
+    let mut previous: HashMap<T, T> = HashMap::new();
+    let mut heap: BinaryHeap<MinScored<W, T>> = BinaryHeap::new();
+
+    if g.adj(&source).is_some() {
+        dist.insert(source.clone(), W::zero());
+        heap.push(MinScored(W::zero(), source));
+    }
+
+    while let Some(MinScored(cost, node)) = heap.pop() {
+        if Some(&node) == target {
+            break;
+        }
+        if cost > dist[&node] {
+            continue;
+        }
+        for neighbor in g.adj(&node).expect("No such node in a graph") {
+            let next = cost + g.weight(&node, neighbor).expect("No such edge in a graph");
+            if dist.get(neighbor).is_none_or(|d| next < *d) {
+                dist.insert(neighbor.clone(), next);
+                previous.insert(neighbor.clone(), node.clone());
+                heap.push(MinScored(next, neighbor.clone()));
+            }
+        }
+    }
+    (dist, previous)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{Directed, Undirected};
+
+    fn weighted_graph() -> WeightedGraph<i8, Directed, u32> {
+        let mut g: WeightedGraph<i8, Directed, u32> = WeightedGraph::new();
+        g.add_edge(1, 2, 7);
+        g.add_edge(1, 3, 2);
+        g.add_edge(3, 2, 3);
+        g.add_edge(2, 4, 1);
+        g.add_node(5);
+        g
+    }
+
+    #[test]
+    fn dijkstra_weighted_path() {
+        let g = weighted_graph();
+        assert_eq!(dijkstra_path(&g, 1, 4), Some((6, vec![1, 3, 2, 4])));
+        assert_eq!(dijkstra_path(&g, 1, 5), None);
+    }
+
+    #[test]
+    fn zero_one_bfs_matches_dijkstra() {
+        let mut g: WeightedGraph<i8, Directed, u8> = WeightedGraph::new();
+        for (u, v, w) in [
+            (1, 2, 1),
+            (1, 3, 0),
+            (3, 4, 1),
+            (4, 2, 0),
+            (2, 5, 1),
+            (4, 5, 1),
+            (5, 6, 0),
+        ] {
+            g.add_edge(u, v, w);
+        }
+        g.add_node(7);
+        for target in 1..=7 {
+            let expected = dijkstra_path(&g, 1, target).map(|(cost, _)| cost as usize);
+            let actual = zero_one_bfs(&g, 1, target);
+            assert_eq!(actual.as_ref().map(|(cost, _)| *cost), expected);
+        }
+        assert_eq!(zero_one_bfs(&g, 1, 4), Some((1, vec![1, 3, 4])));
+        assert_eq!(zero_one_bfs(&g, 1, 7), None);
+    }
+
+    #[test]
+    fn dijkstra_distances() {
+        let g = weighted_graph();
+        let (dist, _) = single_source_dijkstra(&g, 1);
+        assert_eq!(dist, HashMap::from([(1, 0), (2, 5), (3, 2), (4, 6)]));
+    }
+
+    #[test]
+    fn path_above_weight_threshold() {
+        let g = weighted_graph();
+        assert!(!has_path_with_min_weight(&g, 1, 4, 2));
+        assert!(has_path_with_min_weight(&g, 1, 4, 1));
+        assert!(!has_path_with_min_weight(&g, 1, 3, 3));
+        assert!(has_path_with_min_weight(&g, 1, 3, 2));
+        assert!(has_path_with_min_weight(&g, 1, 2, 7));
+        assert!(has_path_with_min_weight(&g, 5, 5, 100));
+        assert!(!has_path_with_min_weight(&g, 4, 1, 0));
+    }
+
+    #[test]
+    fn widest_path_differs_from_shortest() {
+        let mut g: WeightedGraph<i8, Undirected, u32> = WeightedGraph::new();
+        g.add_weighted_edges_from(vec![(1, 2, 1), (2, 4, 2), (1, 3, 5), (3, 5, 4), (5, 4, 6)]);
+        g.add_node(6);
+        assert_eq!(dijkstra_path(&g, 1, 4), Some((3, vec![1, 2, 4])));
+        assert_eq!(widest_path(&g, 1, 4), Some((4, vec![1, 3, 5, 4])));
+        assert_eq!(widest_path(&g, 4, 2), Some((2, vec![4, 2])));
+        assert_eq!(widest_path(&g, 1, 6), None);
+        assert_eq!(widest_path(&g, 1, 1), None);
+    }
+}
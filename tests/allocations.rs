@@ -0,0 +1,58 @@
+//! Allocation checks. A counting allocator replaces the global one for this
+//! test binary only.
+use nxgraph::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts bytes allocated by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATED.try_with(|a| a.set(a.get() + layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocated_by<F: FnOnce() -> bool>(f: F) -> (bool, usize) {
+    let before = ALLOCATED.with(|a| a.get());
+    let result = f();
+    (result, ALLOCATED.with(|a| a.get()) - before)
+}
+
+fn path_graph(n: u64) -> Graph<u64> {
+    let mut g: Graph<u64> = Graph::new();
+    g.add_edges_from((0..n).map(|i| (i, i + 1)).collect());
+    g
+}
+
+#[test]
+fn has_path_allocates_less() {
+    let g = path_graph(2000);
+    let (found, light) = allocated_by(|| BFS::has_path(&g, 0, 2000));
+    let (found_util, heavy) = allocated_by(|| BFS::shortest_path_util(&g, 0, 2000).is_some());
+    assert!(found && found_util);
+    assert!(light < heavy);
+}
+
+#[test]
+fn dijkstra_local_query_allocates_little() {
+    let g = path_graph(20_000);
+    let (found_near, near) =
+        allocated_by(|| Dijkstra::shortest_path_util(&g, 10_000, 10_002).is_some());
+    let (found_far, far) =
+        allocated_by(|| Dijkstra::shortest_path_util(&g, 10_000, 20_000).is_some());
+    assert!(found_near && found_far);
+    assert!(near * 100 < far);
+}
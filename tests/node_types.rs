@@ -0,0 +1,102 @@
+use nxgraph::*;
+use std::collections::HashSet;
+
+fn string_graph() -> Graph<String> {
+    let mut g: Graph<String> = Graph::new();
+    g.add_edges_from(
+        [("a", "b"), ("b", "c"), ("c", "d"), ("a", "e"), ("e", "d")]
+            .into_iter()
+            .map(|(u, v)| (u.to_string(), v.to_string()))
+            .collect(),
+    );
+    g.add_node("f".to_string());
+    g
+}
+
+fn u128_graph() -> Graph<u128> {
+    let big = u128::MAX - 10;
+    let mut g: Graph<u128> = Graph::new();
+    g.add_edges_from(vec![
+        (big, big + 1),
+        (big + 1, big + 2),
+        (big, big + 3),
+        (big + 3, big + 2),
+    ]);
+    g.add_node(0);
+    g
+}
+
+#[test]
+fn string_nodes_search() {
+    let g = string_graph();
+    let (a, d, f) = ("a".to_string(), "d".to_string(), "f".to_string());
+    assert_eq!(BFS::shortest_path_length(&g, a.clone(), d.clone()), Some(2));
+    assert_eq!(
+        Dijkstra::shortest_path_length(&g, a.clone(), d.clone()),
+        Some(2)
+    );
+    assert_eq!(Dijkstra::shortest_path(&g, a.clone(), f.clone()), None);
+    assert!(BFS::has_path(&g, d, a.clone()));
+    assert!(!Dijkstra::has_path(&g, a, f));
+}
+
+#[test]
+fn string_nodes_path_refs() {
+    let g = string_graph();
+    let (a, d, f) = ("a".to_string(), "d".to_string(), "f".to_string());
+    let owned = BFS::shortest_path(&g, a.clone(), d.clone()).unwrap();
+    let borrowed = shortest_path_refs(&g, &a, &d).unwrap();
+    assert_eq!(borrowed, owned.iter().collect::<Vec<_>>());
+    assert_eq!(shortest_path_refs(&g, &a, &a), Some(vec![&a]));
+    assert_eq!(shortest_path_refs(&g, &a, &f), None);
+    assert_eq!(shortest_path_refs(&g, &"z".to_string(), &a), None);
+}
+
+#[test]
+fn u128_nodes_search() {
+    let g = u128_graph();
+    let big = u128::MAX - 10;
+    assert_eq!(BFS::shortest_path_length(&g, big, big + 2), Some(2));
+    assert_eq!(Dijkstra::shortest_path_length(&g, big, big + 2), Some(2));
+    assert_eq!(BFS::shortest_path(&g, big, 0), None);
+}
+
+#[test]
+fn string_nodes_sort() {
+    let mut g: DiGraph<String> = DiGraph::new();
+    g.add_edges_from(
+        [("shirt", "tie"), ("tie", "jacket"), ("trousers", "shoes")]
+            .into_iter()
+            .map(|(u, v)| (u.to_string(), v.to_string()))
+            .collect(),
+    );
+    let sorted = topological_sort(&g).unwrap();
+    let position = |n: &str| sorted.iter().position(|x| x == n).unwrap();
+    assert_eq!(sorted.len(), 5);
+    assert!(position("shirt") < position("tie"));
+    assert!(position("tie") < position("jacket"));
+    assert!(position("trousers") < position("shoes"));
+}
+
+#[test]
+fn u128_nodes_sort() {
+    let mut g: DiGraph<u128> = DiGraph::new();
+    g.add_edges_from(vec![(u128::MAX, 1), (1, 0)]);
+    assert_eq!(topological_sort(&g).unwrap(), vec![u128::MAX, 1, 0]);
+    g.add_edge(0, u128::MAX);
+    assert!(topological_sort(&g).is_err());
+}
+
+#[test]
+fn string_nodes_components() {
+    let g = string_graph();
+    let components = connected_components(&g);
+    assert_eq!(components.len(), 2);
+    assert!(components.contains(&HashSet::from(["f".to_string()])));
+}
+
+#[test]
+fn u128_nodes_components() {
+    let g = u128_graph();
+    assert_eq!(number_connected_components(&g), 2);
+}